@@ -0,0 +1,108 @@
+//! Proc macros of httpc-test, re-exported by `httpc_test` with the `macros` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::Parser;
+use syn::{parse_macro_input, Expr, ItemFn, LitInt};
+
+/// Default number of exchanges printed when a test fails.
+const DEFAULT_HISTORY: usize = 10;
+
+/// Async test (`#[tokio::test]`) provided with a ready `httpc_test::Client` as its argument.
+///
+/// Arguments (all optional):
+/// - `base_url = <expr>` the client base url (e.g., `env!("API_BASE_URL")`)
+/// - `login = <path>` async fn called with `&Client` before the test, returning a `Result`
+/// - `teardown = <path>` async fn called with `&Client` after the test (even if it failed), returning a `Result`
+/// - `history = <int>` number of the last exchanges printed when the test fails (default 10)
+///
+/// The test fails if it panics or returns an `Err`, in which case the last exchanges of the client are printed.
+#[proc_macro_attribute]
+pub fn test(args: TokenStream, item: TokenStream) -> TokenStream {
+	let item_fn = parse_macro_input!(item as ItemFn);
+	match expand_test(args.into(), item_fn) {
+		Ok(tokens) => tokens.into(),
+		Err(err) => err.to_compile_error().into(),
+	}
+}
+
+#[derive(Default)]
+struct TestArgs {
+	base_url: Option<Expr>,
+	login: Option<Expr>,
+	teardown: Option<Expr>,
+	history: Option<LitInt>,
+}
+
+fn expand_test(args: TokenStream2, item_fn: ItemFn) -> syn::Result<TokenStream2> {
+	let mut test_args = TestArgs::default();
+	let args_parser = syn::meta::parser(|meta| {
+		if meta.path.is_ident("base_url") {
+			test_args.base_url = Some(meta.value()?.parse()?);
+		} else if meta.path.is_ident("login") {
+			test_args.login = Some(meta.value()?.parse()?);
+		} else if meta.path.is_ident("teardown") {
+			test_args.teardown = Some(meta.value()?.parse()?);
+		} else if meta.path.is_ident("history") {
+			test_args.history = Some(meta.value()?.parse()?);
+		} else {
+			return Err(meta.error("unsupported httpc_test::test argument (base_url, login, teardown, history)"));
+		}
+		Ok(())
+	});
+	args_parser.parse2(args)?;
+
+	if item_fn.sig.asyncness.is_none() {
+		return Err(syn::Error::new_spanned(
+			item_fn.sig.fn_token,
+			"httpc_test::test functions must be async",
+		));
+	}
+	if item_fn.sig.inputs.len() > 1 {
+		return Err(syn::Error::new_spanned(
+			&item_fn.sig.inputs,
+			"httpc_test::test functions take at most one argument (the httpc_test::Client)",
+		));
+	}
+
+	let ItemFn { attrs, vis, sig, block } = item_fn;
+	let name = &sig.ident;
+	let output = &sig.output;
+	let inputs = &sig.inputs;
+
+	let base_url = match test_args.base_url {
+		Some(base_url) => quote! { #base_url },
+		None => quote! { ::std::option::Option::<::std::string::String>::None },
+	};
+	let history = match test_args.history {
+		Some(history) => quote! { #history },
+		None => quote! { #DEFAULT_HISTORY },
+	};
+	let login = test_args.login.map(|login| {
+		quote! { ::httpc_test::__private::check_step(&__hc, "login", #login(&__hc).await); }
+	});
+	let teardown = match test_args.teardown {
+		Some(teardown) => quote! { ::httpc_test::__private::failure(&#teardown(&__hc).await) },
+		None => quote! { ::std::option::Option::None },
+	};
+	let body_call = if inputs.is_empty() {
+		quote! { __httpc_test_body() }
+	} else {
+		quote! { __httpc_test_body(__hc.clone()) }
+	};
+
+	Ok(quote! {
+		#[::httpc_test::__private::tokio::test(crate = "::httpc_test::__private::tokio")]
+		#(#attrs)*
+		#vis async fn #name() #output {
+			async fn __httpc_test_body(#inputs) #output #block
+
+			let __hc = ::httpc_test::__private::new_client(#base_url, #history);
+			#login
+			let __outcome = ::httpc_test::__private::catch_unwind(#body_call).await;
+			let __teardown_failure = #teardown;
+			::httpc_test::__private::finish(&__hc, __outcome, __teardown_failure)
+		}
+	})
+}
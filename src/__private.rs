@@ -0,0 +1,97 @@
+//! Support of the `#[httpc_test::test]` macro expansion (not a public API).
+
+use crate::client::BaseUrl;
+use crate::Client;
+use std::any::Any;
+use std::fmt::Debug;
+use std::future::Future;
+use std::panic::{catch_unwind as std_catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+// Re-exported for the `#[tokio::test]` of the expansion, so that the test crate does not need a tokio dependency.
+pub use tokio;
+
+/// The return value of a test (or of its login/teardown), which is a failure if `Err`.
+pub trait TestOutcome {
+	fn failure(&self) -> Option<String>;
+}
+
+impl TestOutcome for () {
+	fn failure(&self) -> Option<String> {
+		None
+	}
+}
+
+impl<T, E: Debug> TestOutcome for Result<T, E> {
+	fn failure(&self) -> Option<String> {
+		self.as_ref().err().map(|err| format!("{err:?}"))
+	}
+}
+
+pub fn failure(outcome: &impl TestOutcome) -> Option<String> {
+	outcome.failure()
+}
+
+pub fn new_client(base_url: impl Into<BaseUrl>, history: usize) -> Client {
+	match crate::new_client(base_url) {
+		Ok(client) => client.with_history(history),
+		Err(err) => panic!("httpc_test::test - cannot create the client: {err}"),
+	}
+}
+
+/// Print the history and panic if the step (e.g., login) failed.
+pub fn check_step(client: &Client, step: &str, outcome: impl TestOutcome) {
+	if let Some(failure) = outcome.failure() {
+		print_history(client);
+		panic!("httpc_test::test - {step} failed: {failure}");
+	}
+}
+
+/// Return the test outcome, printing the history if the test (or its teardown) failed.
+pub fn finish<T: TestOutcome>(
+	client: &Client,
+	outcome: Result<T, Box<dyn Any + Send>>,
+	teardown_failure: Option<String>,
+) -> T {
+	let test_failed = outcome.as_ref().map(|o| o.failure().is_some()).unwrap_or(true);
+	if test_failed || teardown_failure.is_some() {
+		print_history(client);
+	}
+	if let Some(failure) = &teardown_failure {
+		eprintln!("httpc_test::test - teardown failed: {failure}");
+	}
+
+	match outcome {
+		Err(panic) => resume_unwind(panic),
+		Ok(outcome) if !test_failed && teardown_failure.is_some() => {
+			drop(outcome);
+			panic!("httpc_test::test - teardown failed")
+		}
+		Ok(outcome) => outcome,
+	}
+}
+
+fn print_history(client: &Client) {
+	print!("{}", client.history_pretty_string());
+}
+
+/// Run the future, catching its panic (if any).
+pub async fn catch_unwind<F: Future>(future: F) -> Result<F::Output, Box<dyn Any + Send>> {
+	CatchUnwind(Box::pin(future)).await
+}
+
+struct CatchUnwind<F>(Pin<Box<F>>);
+
+impl<F: Future> Future for CatchUnwind<F> {
+	type Output = Result<F::Output, Box<dyn Any + Send>>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let future = self.0.as_mut();
+		match std_catch_unwind(AssertUnwindSafe(|| future.poll(cx))) {
+			Ok(Poll::Pending) => Poll::Pending,
+			Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+			Err(panic) => Poll::Ready(Err(panic)),
+		}
+	}
+}
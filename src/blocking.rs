@@ -0,0 +1,341 @@
+//! Blocking (synchronous) client, for the harnesses and build scripts without an async runtime.
+//!
+//! Note: The calls are run on a tokio current thread runtime owned by the client,
+//!       so this client must not be used from within an async runtime (it would panic).
+
+use crate::client::{BaseUrl, PostContent};
+use crate::cookie::Cookie;
+use crate::{
+	CurlRequest, GraphQLResponse, Middleware, PrintOptions, Redaction, Response, Result, RetryPolicy, RpcResult,
+};
+use reqwest_cookie_store::CookieStoreMutex;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Blocking version of `httpc_test::Client`, returning the same `Response`
+/// (use the `print_sync` methods to print it).
+///
+/// Note: Cloning a client is cheap, and the clones share the same cookie store and runtime.
+#[derive(Clone)]
+pub struct Client {
+	inner: crate::Client,
+	runtime: Arc<Runtime>,
+}
+
+pub fn new_client(base_url: impl Into<BaseUrl>) -> Result<Client> {
+	Client::from_async(crate::new_client(base_url)?)
+}
+
+pub fn new_client_with_reqwest(
+	base_url: impl Into<BaseUrl>,
+	reqwest_builder: reqwest::ClientBuilder,
+) -> Result<Client> {
+	Client::from_async(crate::new_client_with_reqwest(base_url, reqwest_builder)?)
+}
+
+impl Client {
+	/// Wrap an async client (e.g., one configured before being used from a synchronous harness).
+	pub fn from_async(inner: crate::Client) -> Result<Self> {
+		let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+		Ok(Client {
+			inner,
+			runtime: Arc::new(runtime),
+		})
+	}
+
+	/// The underlying async client.
+	pub fn async_client(&self) -> &crate::Client {
+		&self.inner
+	}
+
+	fn block_on<F: Future>(&self, future: F) -> F::Output {
+		self.runtime.block_on(future)
+	}
+
+	fn map_inner(self, f: impl FnOnce(crate::Client) -> crate::Client) -> Self {
+		Client {
+			inner: f(self.inner),
+			runtime: self.runtime,
+		}
+	}
+}
+
+// region:    --- Configuration
+impl Client {
+	pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+		self.map_inner(|c| c.with_retry_policy(retry_policy))
+	}
+
+	pub fn with_print_options(self, print_options: PrintOptions) -> Self {
+		self.map_inner(|c| c.with_print_options(print_options))
+	}
+
+	pub fn with_redaction(self, redaction: Redaction) -> Self {
+		self.map_inner(|c| c.with_redaction(redaction))
+	}
+
+	pub fn with_error_for_status(self, error_for_status: bool) -> Self {
+		self.map_inner(|c| c.with_error_for_status(error_for_status))
+	}
+
+	pub fn with_follow_redirects(self, follow_redirects: bool) -> Self {
+		self.map_inner(|c| c.with_follow_redirects(follow_redirects))
+	}
+
+	pub fn with_default_headers(self, headers: reqwest::header::HeaderMap) -> Self {
+		self.map_inner(|c| c.with_default_headers(headers))
+	}
+
+	#[cfg(feature = "openapi")]
+	pub fn with_openapi(self, openapi: crate::OpenApi) -> Self {
+		self.map_inner(|c| c.with_openapi(openapi))
+	}
+
+	pub fn with_history(self, max: usize) -> Self {
+		self.map_inner(|c| c.with_history(max))
+	}
+
+	pub fn with_middleware(self, middleware: impl Middleware + 'static) -> Self {
+		self.map_inner(|c| c.with_middleware(middleware))
+	}
+
+	pub fn with_session_name(self, name: impl Into<String>) -> Self {
+		self.map_inner(|c| c.with_session_name(name))
+	}
+
+	pub fn session_name(&self) -> Option<&str> {
+		self.inner.session_name()
+	}
+
+	pub fn cookie_store(&self) -> Arc<CookieStoreMutex> {
+		self.inner.cookie_store()
+	}
+
+	/// Same client with an empty cookie store (see `httpc_test::Client::new_session`).
+	pub fn new_session(&self) -> Client {
+		self.clone().map_inner(|c| c.new_session())
+	}
+
+	/// Same client with a copy of the current cookies (see `httpc_test::Client::fork_session`).
+	pub fn fork_session(&self) -> Client {
+		self.clone().map_inner(|c| c.fork_session())
+	}
+}
+// endregion: --- Configuration
+
+// region:    --- http calls returning httpc-test Response
+impl Client {
+	pub fn do_get(&self, url: &str) -> Result<Response> {
+		self.block_on(self.inner.do_get(url))
+	}
+
+	pub fn do_delete(&self, url: &str) -> Result<Response> {
+		self.block_on(self.inner.do_delete(url))
+	}
+
+	pub fn do_post(&self, url: &str, content: impl Into<PostContent>) -> Result<Response> {
+		self.block_on(self.inner.do_post(url, content))
+	}
+
+	pub fn do_put(&self, url: &str, content: impl Into<PostContent>) -> Result<Response> {
+		self.block_on(self.inner.do_put(url, content))
+	}
+
+	pub fn do_patch(&self, url: &str, content: impl Into<PostContent>) -> Result<Response> {
+		self.block_on(self.inner.do_patch(url, content))
+	}
+
+	pub fn do_curl(&self, command: &str) -> Result<Response> {
+		self.block_on(self.inner.do_curl(command))
+	}
+
+	pub fn do_curl_request(&self, req: &CurlRequest) -> Result<Response> {
+		self.block_on(self.inner.do_curl_request(req))
+	}
+}
+// endregion: --- http calls returning httpc-test Response
+
+// region:    --- http calls returning typed Deserialized body
+impl Client {
+	pub fn get<T>(&self, url: &str) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		self.block_on(self.inner.get::<T>(url))
+	}
+
+	pub fn delete<T>(&self, url: &str) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		self.block_on(self.inner.delete::<T>(url))
+	}
+
+	pub fn post<T>(&self, url: &str, content: impl Into<PostContent>) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		self.block_on(self.inner.post::<T>(url, content))
+	}
+
+	pub fn put<T>(&self, url: &str, content: impl Into<PostContent>) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		self.block_on(self.inner.put::<T>(url, content))
+	}
+
+	pub fn patch<T>(&self, url: &str, content: impl Into<PostContent>) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		self.block_on(self.inner.patch::<T>(url, content))
+	}
+
+	pub fn get_result<T, E>(&self, url: &str) -> Result<std::result::Result<T, E>>
+	where
+		T: DeserializeOwned,
+		E: DeserializeOwned,
+	{
+		self.block_on(self.inner.get_result::<T, E>(url))
+	}
+
+	pub fn delete_result<T, E>(&self, url: &str) -> Result<std::result::Result<T, E>>
+	where
+		T: DeserializeOwned,
+		E: DeserializeOwned,
+	{
+		self.block_on(self.inner.delete_result::<T, E>(url))
+	}
+
+	pub fn post_result<T, E>(&self, url: &str, content: impl Into<PostContent>) -> Result<std::result::Result<T, E>>
+	where
+		T: DeserializeOwned,
+		E: DeserializeOwned,
+	{
+		self.block_on(self.inner.post_result::<T, E>(url, content))
+	}
+
+	pub fn put_result<T, E>(&self, url: &str, content: impl Into<PostContent>) -> Result<std::result::Result<T, E>>
+	where
+		T: DeserializeOwned,
+		E: DeserializeOwned,
+	{
+		self.block_on(self.inner.put_result::<T, E>(url, content))
+	}
+
+	pub fn patch_result<T, E>(&self, url: &str, content: impl Into<PostContent>) -> Result<std::result::Result<T, E>>
+	where
+		T: DeserializeOwned,
+		E: DeserializeOwned,
+	{
+		self.block_on(self.inner.patch_result::<T, E>(url, content))
+	}
+}
+// endregion: --- http calls returning typed Deserialized body
+
+// region:    --- GraphQL & JSON-RPC
+impl Client {
+	pub fn graphql(&self, url: &str, query: &str, variables: Value) -> Result<GraphQLResponse> {
+		self.block_on(self.inner.graphql(url, query, variables))
+	}
+
+	pub fn rpc_call<T>(&self, url: &str, method: &str, params: Value) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		self.block_on(self.inner.rpc_call::<T>(url, method, params))
+	}
+
+	pub fn rpc_batch(
+		&self,
+		url: &str,
+		calls: impl IntoIterator<Item = (impl Into<String>, Value)>,
+	) -> Result<Vec<RpcResult>> {
+		self.block_on(self.inner.rpc_batch(url, calls))
+	}
+}
+// endregion: --- GraphQL & JSON-RPC
+
+// region:    --- Poll
+impl Client {
+	/// Start a poll on a GET url, to be completed with `.until(|res| ...)`.
+	pub fn poll(&self, url: &str) -> Poll<'_> {
+		Poll {
+			inner: self.inner.poll(url),
+			runtime: &self.runtime,
+		}
+	}
+}
+
+/// Blocking version of `httpc_test::Poll`, returned by `Client::poll(url)`.
+pub struct Poll<'a> {
+	inner: crate::Poll<'a>,
+	runtime: &'a Runtime,
+}
+
+impl<'a> Poll<'a> {
+	pub fn interval(self, interval: Duration) -> Self {
+		self.map_inner(|inner| inner.interval(interval))
+	}
+
+	pub fn backoff(self, factor: f64, max_interval: Duration) -> Self {
+		self.map_inner(|inner| inner.backoff(factor, max_interval))
+	}
+
+	pub fn timeout(self, timeout: Duration) -> Self {
+		self.map_inner(|inner| inner.timeout(timeout))
+	}
+
+	pub fn until<F>(self, predicate: F) -> Result<Response>
+	where
+		F: Fn(&Response) -> bool,
+	{
+		self.runtime.block_on(self.inner.until(predicate))
+	}
+
+	fn map_inner(self, f: impl FnOnce(crate::Poll<'a>) -> crate::Poll<'a>) -> Self {
+		Poll {
+			inner: f(self.inner),
+			runtime: self.runtime,
+		}
+	}
+}
+// endregion: --- Poll
+
+// region:    --- History
+impl Client {
+	pub fn history(&self) -> Vec<Response> {
+		self.inner.history()
+	}
+
+	pub fn last_response(&self) -> Option<Response> {
+		self.inner.last_response()
+	}
+
+	pub fn clear_history(&self) {
+		self.inner.clear_history()
+	}
+
+	pub fn print_history_sync(&self) -> Result<()> {
+		print!("{}", self.inner.history_pretty_string());
+		Ok(())
+	}
+}
+// endregion: --- History
+
+// region:    --- Cookie
+impl Client {
+	pub fn cookie(&self, name: &str) -> Option<Cookie> {
+		self.inner.cookie(name)
+	}
+
+	pub fn cookie_value(&self, name: &str) -> Option<String> {
+		self.inner.cookie_value(name)
+	}
+}
+// endregion: --- Cookie
@@ -1,44 +1,241 @@
 use crate::cookie::{from_tower_cookie_deref, Cookie};
+use crate::curl::CurlRequest;
+use crate::graphql::GraphQLResponse;
+use crate::history::History;
+use crate::load::LoadTest;
+use crate::middleware::{Middleware, RequestParts};
+use crate::poll::Poll;
+use crate::print_options::PrintOptions;
+use crate::redaction::Redaction;
+use crate::response::Redirect;
+use crate::retry::{Attempt, AttemptOutcome, RetryPolicy};
+use crate::rpc::{self, RpcResult};
+use crate::stream::StreamResponse;
 use crate::{Error, Response, Result};
-use reqwest::Method;
+use reqwest::cookie::CookieStore;
+use reqwest::header::{
+	HeaderMap, HeaderName, HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE, COOKIE, LOCATION, PROXY_AUTHORIZATION,
+	SET_COOKIE, WWW_AUTHENTICATE,
+};
+use reqwest::{Method, StatusCode, Url};
 use reqwest_cookie_store::CookieStoreMutex;
 use serde::de::DeserializeOwned;
-use serde_json::Value;
-use std::sync::Arc;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Maximum number of redirects followed for one call (same as the reqwest default policy).
+const MAX_REDIRECTS: usize = 10;
+
+/// True if the redirect is followed with a GET without body,
+/// which is the case of 303, and 301/302 for POST (as browsers and reqwest do).
+fn redirected_as_get(method: &Method, status: StatusCode) -> bool {
+	status == StatusCode::SEE_OTHER
+		|| (method == Method::POST && matches!(status, StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND))
+}
+
+/// True if the urls have a different scheme, host, or port (the credentials are not sent to another origin).
+fn is_cross_origin(url: &Url, next_url: &Url) -> bool {
+	url.scheme() != next_url.scheme()
+		|| url.host_str() != next_url.host_str()
+		|| url.port_or_known_default() != next_url.port_or_known_default()
+}
+
+/// Remove the credentials headers before following a redirect to another origin (as reqwest does).
+/// Note: The reqwest client default headers are added by reqwest to each request, so cannot be removed
+///       (use `Client::with_default_headers` for the credentials).
+fn remove_sensitive_headers(headers: &mut HeaderMap) {
+	for name in [AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION, WWW_AUTHENTICATE] {
+		headers.remove(name);
+	}
+	headers.remove("cookie2");
+}
 
+/// Note: Cloning a client is cheap, and the clones share the same cookie store.
+///       Use `new_session()` or `fork_session()` for a client with its own cookie store.
+#[derive(Clone)]
 pub struct Client {
 	base_url: Option<String>,
+	session_name: Option<String>,
 	cookie_store: Arc<CookieStoreMutex>,
 	reqwest_client: reqwest::Client,
+	retry_policy: Option<RetryPolicy>,
+	print_options: PrintOptions,
+	redaction: Arc<Redaction>,
+	error_for_status: bool,
+	follow_redirects: bool,
+	/// Headers added to each request (before the middlewares)
+	default_headers: HeaderMap,
+	/// OpenAPI document the responses are validated against
+	#[cfg(feature = "openapi")]
+	openapi: Option<Arc<crate::openapi::OpenApi>>,
+	/// Last responses (shared by the clones), when enabled
+	history: Option<Arc<Mutex<History>>>,
+	/// Hooks run for each call, in order
+	middlewares: Vec<Arc<dyn Middleware>>,
+	/// Next JSON-RPC request id (shared by the clones)
+	rpc_next_id: Arc<AtomicU64>,
 }
 
 impl Client {
+	/// Set the retry policy applied to every `do_...` call (and the typed helpers).
+	pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.retry_policy = Some(retry_policy);
+		self
+	}
+
+	pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+		self.retry_policy.as_ref()
+	}
+
+	/// Set the default print options of the responses of this client (used by `Response::print`).
+	pub fn with_print_options(mut self, print_options: PrintOptions) -> Self {
+		self.print_options = print_options;
+		self
+	}
+
+	pub fn print_options(&self) -> &PrintOptions {
+		&self.print_options
+	}
+
+	/// Set the redaction rules applied when printing the responses of this client (default: `Redaction::default()`).
+	pub fn with_redaction(mut self, redaction: Redaction) -> Self {
+		self.redaction = Arc::new(redaction);
+		self
+	}
+
+	pub fn redaction(&self) -> &Redaction {
+		&self.redaction
+	}
+
+	pub(crate) fn redaction_arc(&self) -> Arc<Redaction> {
+		self.redaction.clone()
+	}
+
+	/// When true, the typed helpers (`get`, `post`, ...) return an `Error::HttpStatus` (with the response)
+	/// for 4xx and 5xx responses, rather than deserializing their body (default false).
+	pub fn with_error_for_status(mut self, error_for_status: bool) -> Self {
+		self.error_for_status = error_for_status;
+		self
+	}
+
+	/// When false, the 3xx responses are returned as is (e.g., to check the `Location` of a login redirect).
+	/// Default: true (up to 10 redirects, recorded in `Response::redirects()`)
+	/// Note: The credentials headers (e.g., `Authorization` set by a middleware or curl command)
+	///       are not sent to another origin.
+	pub fn with_follow_redirects(mut self, follow_redirects: bool) -> Self {
+		self.follow_redirects = follow_redirects;
+		self
+	}
+
+	/// Set the headers added to each request (replaced by the headers of the call or of a middleware).
+	/// Note: Unlike the reqwest client default headers, the credentials headers (e.g., `Authorization`)
+	///       are not sent to another origin when following a redirect.
+	pub fn with_default_headers(mut self, headers: HeaderMap) -> Self {
+		self.default_headers = headers;
+		self
+	}
+
+	/// Validate all the responses against the OpenAPI document.
+	/// The non conforming responses are returned as `Error::OpenApiViolations`.
+	#[cfg(feature = "openapi")]
+	pub fn with_openapi(mut self, openapi: crate::openapi::OpenApi) -> Self {
+		self.openapi = Some(Arc::new(openapi));
+		self
+	}
+
+	/// Add a middleware, run for each `do_...` call after the ones already added (see `Middleware`).
+	pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+		self.middlewares.push(Arc::new(middleware));
+		self
+	}
+
+	/// Name this client session (e.g., the user it is logged in as), shown in the `Response::print` header.
+	pub fn with_session_name(mut self, name: impl Into<String>) -> Self {
+		self.session_name = Some(name.into());
+		self
+	}
+
+	pub fn session_name(&self) -> Option<&str> {
+		self.session_name.as_deref()
+	}
+
 	pub fn cookie_store(&self) -> Arc<CookieStoreMutex> {
 		self.cookie_store.clone()
 	}
+
+	/// Note: The cookies are managed by this httpc-test Client, not by the underlying reqwest client.
 	pub fn reqwest_client(&self) -> &reqwest::Client {
 		&self.reqwest_client
 	}
 }
 
+// region:    --- Sessions
+impl Client {
+	/// Return a new client with the same base URL, reqwest client (settings, default headers),
+	/// and policies, but with a new empty cookie store.
+	/// Note: The session name is not carried over; use `.with_session_name(..)` to name the new session.
+	pub fn new_session(&self) -> Client {
+		Client {
+			session_name: None,
+			cookie_store: Arc::new(CookieStoreMutex::default()),
+			..self.clone()
+		}
+	}
+
+	/// Same as `new_session()`, but the new cookie store starts with a copy of the current cookies.
+	pub fn fork_session(&self) -> Client {
+		let cookie_store = self.cookie_store.lock().unwrap().clone();
+		Client {
+			session_name: None,
+			cookie_store: Arc::new(CookieStoreMutex::new(cookie_store)),
+			..self.clone()
+		}
+	}
+}
+// endregion: --- Sessions
+
 pub fn new_client(base_url: impl Into<BaseUrl>) -> Result<Client> {
 	let reqwest_builder = reqwest::Client::builder();
 
 	new_client_with_reqwest(base_url, reqwest_builder)
 }
 
+/// Note: The cookies and redirects are handled by the httpc-test Client (per session),
+///       so the builder's cookie provider and redirect policy are replaced (breaking change in 0.2.0):
+///       - Use `Client::with_follow_redirects(false)` instead of `redirect(Policy::none())`.
+///       - The requests made directly with `Client::reqwest_client()` do not have the session cookies.
+///       - The builder default headers are sent on each redirect, even to another origin
+///         (use `Client::with_default_headers` for the credentials).
 pub fn new_client_with_reqwest(
 	base_url: impl Into<BaseUrl>,
 	reqwest_builder: reqwest::ClientBuilder,
 ) -> Result<Client> {
 	let base_url = base_url.into().into();
 	let cookie_store = Arc::new(CookieStoreMutex::default());
-	let reqwest_client = reqwest_builder.cookie_provider(cookie_store.clone()).build()?;
+	let reqwest_client = reqwest_builder
+		.cookie_store(false)
+		.redirect(reqwest::redirect::Policy::none())
+		.build()?;
 
 	Ok(Client {
 		base_url,
+		session_name: None,
 		cookie_store,
 		reqwest_client,
+		retry_policy: None,
+		print_options: PrintOptions::default(),
+		redaction: Arc::new(Redaction::default()),
+		error_for_status: false,
+		follow_redirects: true,
+		default_headers: HeaderMap::new(),
+		#[cfg(feature = "openapi")]
+		openapi: None,
+		history: None,
+		middlewares: Vec::new(),
+		rpc_next_id: Arc::new(AtomicU64::new(1)),
 	})
 }
 
@@ -46,14 +243,12 @@ impl Client {
 	// region:    --- http calls returning httpc-test Response
 	pub async fn do_get(&self, url: &str) -> Result<Response> {
 		let url = self.compose_url(url);
-		let reqwest_res = self.reqwest_client.get(&url).send().await?;
-		self.capture_response(Method::GET, url, reqwest_res).await
+		self.execute(Method::GET, url, None, HeaderMap::new()).await
 	}
 
 	pub async fn do_delete(&self, url: &str) -> Result<Response> {
 		let url = self.compose_url(url);
-		let reqwest_res = self.reqwest_client.delete(&url).send().await?;
-		self.capture_response(Method::DELETE, url, reqwest_res).await
+		self.execute(Method::DELETE, url, None, HeaderMap::new()).await
 	}
 
 	pub async fn do_post(&self, url: &str, content: impl Into<PostContent>) -> Result<Response> {
@@ -67,45 +262,303 @@ impl Client {
 	pub async fn do_patch(&self, url: &str, content: impl Into<PostContent>) -> Result<Response> {
 		self.do_push(Method::PATCH, url, content.into()).await
 	}
+
+	/// Parse and send a curl command (e.g., copied from the browser devtools), see `CurlRequest`.
+	pub async fn do_curl(&self, command: &str) -> Result<Response> {
+		self.do_curl_request(&CurlRequest::parse(command)?).await
+	}
+
+	/// Send the curl request, with its cookies added to the client session.
+	/// Note: A relative url (e.g., `/api/tickets`) is composed with the client base url.
+	pub async fn do_curl_request(&self, req: &CurlRequest) -> Result<Response> {
+		let url = if req.url.starts_with('/') {
+			self.compose_url(&req.url)
+		} else if !req.url.contains("://") {
+			format!("http://{}", req.url)
+		} else {
+			req.url.clone()
+		};
+
+		if !req.cookies.is_empty() {
+			let cookie_url = Url::parse(&url).map_err(|err| Error::Generic(format!("Invalid url '{url}': {err}")))?;
+			let cookies: Vec<HeaderValue> = req
+				.cookies
+				.iter()
+				.filter_map(|(name, value)| HeaderValue::from_str(&format!("{name}={value}")).ok())
+				.collect();
+			self.cookie_store.set_cookies(&mut cookies.iter(), &cookie_url);
+		}
+
+		// Note: The accept-encoding of the browser is not sent, as the response would not be decompressed.
+		let mut headers = HeaderMap::new();
+		for (name, value) in req
+			.headers
+			.iter()
+			.filter(|(n, _)| !n.eq_ignore_ascii_case("accept-encoding"))
+		{
+			let name = HeaderName::from_bytes(name.as_bytes()).map_err(|err| Error::CurlParse {
+				reason: format!("invalid header name '{name}': {err}"),
+			})?;
+			let value = HeaderValue::from_str(value).map_err(|err| Error::CurlParse {
+				reason: format!("invalid header value '{value}': {err}"),
+			})?;
+			headers.append(name, value);
+		}
+
+		// The content type header (if any) replaces the default one of the content.
+		let content = req.body.clone().map(|body| PostContent::Text {
+			body,
+			content_type: if req.json {
+				"application/json"
+			} else {
+				"application/x-www-form-urlencoded"
+			},
+		});
+		if req.json && req.header("accept").is_none() {
+			headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+		}
+
+		self.execute(req.method.clone(), url, content, headers).await
+	}
 	// endregion: --- http calls returning httpc-test Response
 
+	// region:    --- http calls returning streaming Response
+	/// GET returning as soon as the status and headers are received, with the body to be read
+	/// as a stream (e.g., `res.into_sse()` for Server-Sent Events).
+	/// Note: Only the `Middleware::before_request` hooks are run, as there is no `Response`.
+	pub async fn do_get_stream(&self, url: &str) -> Result<StreamResponse> {
+		let url = self.compose_url(url);
+		let parts = self.before_request(Method::GET, url, None, HeaderMap::new()).await?;
+		let sent = self
+			.send_with_retry(&parts.method, &parts.url, parts.body.as_ref(), &parts.headers)
+			.await?;
+		Ok(StreamResponse::new(parts.method, parts.url, sent.reqwest_res))
+	}
+	// endregion: --- http calls returning streaming Response
+
+	// region:    --- WebSocket
+	/// Connect a WebSocket on the url (prefixed by the base url, with `http` replaced by `ws`),
+	/// sending the cookies of this client session.
+	#[cfg(feature = "ws")]
+	pub async fn ws_connect(&self, url: &str) -> Result<crate::ws::WsClient> {
+		crate::ws::connect(self, self.compose_url(url)).await
+	}
+	// endregion: --- WebSocket
+
 	// region:    --- http calls returning typed Deserialized body
+	// Note: With `with_error_for_status(true)`, 4xx and 5xx responses are returned as `Error::HttpStatus`.
 	pub async fn get<T>(&self, url: &str) -> Result<T>
 	where
 		T: DeserializeOwned,
 	{
-		self.do_get(url).await.and_then(|res| res.json_body_as::<T>())
+		self.do_get(url).await.and_then(|res| self.typed_body::<T>(res))
 	}
 
 	pub async fn delete<T>(&self, url: &str) -> Result<T>
 	where
 		T: DeserializeOwned,
 	{
-		self.do_delete(url).await.and_then(|res| res.json_body_as::<T>())
+		self.do_delete(url).await.and_then(|res| self.typed_body::<T>(res))
 	}
 
 	pub async fn post<T>(&self, url: &str, content: impl Into<PostContent>) -> Result<T>
 	where
 		T: DeserializeOwned,
 	{
-		self.do_post(url, content).await.and_then(|res| res.json_body_as::<T>())
+		self.do_post(url, content).await.and_then(|res| self.typed_body::<T>(res))
 	}
 
 	pub async fn put<T>(&self, url: &str, content: impl Into<PostContent>) -> Result<T>
 	where
 		T: DeserializeOwned,
 	{
-		self.do_put(url, content).await.and_then(|res| res.json_body_as::<T>())
+		self.do_put(url, content).await.and_then(|res| self.typed_body::<T>(res))
 	}
 
 	pub async fn patch<T>(&self, url: &str, content: impl Into<PostContent>) -> Result<T>
 	where
 		T: DeserializeOwned,
 	{
-		self.do_patch(url, content).await.and_then(|res| res.json_body_as::<T>())
+		self.do_patch(url, content).await.and_then(|res| self.typed_body::<T>(res))
 	}
 	// endregion: --- http calls returning typed Deserialized body
 
+	// region:    --- http calls returning typed Deserialized body or error body
+	// Note: Deserialize the body as `T` for 2xx responses, and as the error body `E` otherwise
+	//       (e.g., `hc.get_result::<Ticket, ProblemDetails>("/api/tickets/1")`).
+	pub async fn get_result<T, E>(&self, url: &str) -> Result<std::result::Result<T, E>>
+	where
+		T: DeserializeOwned,
+		E: DeserializeOwned,
+	{
+		self.do_get(url).await.and_then(|res| res.json_result_as::<T, E>())
+	}
+
+	pub async fn delete_result<T, E>(&self, url: &str) -> Result<std::result::Result<T, E>>
+	where
+		T: DeserializeOwned,
+		E: DeserializeOwned,
+	{
+		self.do_delete(url).await.and_then(|res| res.json_result_as::<T, E>())
+	}
+
+	pub async fn post_result<T, E>(&self, url: &str, content: impl Into<PostContent>) -> Result<std::result::Result<T, E>>
+	where
+		T: DeserializeOwned,
+		E: DeserializeOwned,
+	{
+		self.do_post(url, content).await.and_then(|res| res.json_result_as::<T, E>())
+	}
+
+	pub async fn put_result<T, E>(&self, url: &str, content: impl Into<PostContent>) -> Result<std::result::Result<T, E>>
+	where
+		T: DeserializeOwned,
+		E: DeserializeOwned,
+	{
+		self.do_put(url, content).await.and_then(|res| res.json_result_as::<T, E>())
+	}
+
+	pub async fn patch_result<T, E>(&self, url: &str, content: impl Into<PostContent>) -> Result<std::result::Result<T, E>>
+	where
+		T: DeserializeOwned,
+		E: DeserializeOwned,
+	{
+		self.do_patch(url, content).await.and_then(|res| res.json_result_as::<T, E>())
+	}
+	// endregion: --- http calls returning typed Deserialized body or error body
+
+	// region:    --- GraphQL
+	/// POST a GraphQL query (`{"query": .., "variables": ..}`) on the url.
+	pub async fn graphql(&self, url: &str, query: &str, variables: Value) -> Result<GraphQLResponse> {
+		let body = json!({
+			"query": query,
+			"variables": variables,
+		});
+		let response = self.do_post(url, body).await?;
+		Ok(GraphQLResponse::new(query.to_string(), variables, response))
+	}
+	// endregion: --- GraphQL
+
+	// region:    --- JSON-RPC
+	/// POST a JSON-RPC 2.0 call on the url, and return its deserialized `result`.
+	/// A JSON-RPC error response is returned as `Error::Rpc`.
+	/// Note: Use `Value::Null` as `params` for a method without params (omitted from the request).
+	pub async fn rpc_call<T>(&self, url: &str, method: &str, params: Value) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		let id = self.rpc_next_id.fetch_add(1, Ordering::SeqCst);
+		let res = rpc::json_body(self.do_post(url, rpc::request(method, params, id)).await?)?;
+
+		let result = rpc::into_result(res, id)?.map_err(Error::Rpc)?;
+		Ok(serde_json::from_value::<T>(result)?)
+	}
+
+	/// POST a JSON-RPC 2.0 batch of `(method, params)` calls on the url,
+	/// and return the results in the order of the calls (correlated by id).
+	pub async fn rpc_batch(
+		&self,
+		url: &str,
+		calls: impl IntoIterator<Item = (impl Into<String>, Value)>,
+	) -> Result<Vec<RpcResult>> {
+		let (ids, requests): (Vec<u64>, Vec<Value>) = calls
+			.into_iter()
+			.map(|(method, params)| {
+				let id = self.rpc_next_id.fetch_add(1, Ordering::SeqCst);
+				(id, rpc::request(&method.into(), params, id))
+			})
+			.unzip();
+
+		let res = rpc::json_body(self.do_post(url, Value::Array(requests)).await?)?;
+		let Value::Array(items) = res else {
+			return Err(Error::RpcInvalidResponse {
+				reason: format!("batch response is not a json array: {res}"),
+			});
+		};
+
+		let mut by_id: HashMap<u64, Value> = HashMap::new();
+		for item in items {
+			match item.get("id").and_then(Value::as_u64) {
+				Some(id) if ids.contains(&id) => {
+					by_id.insert(id, item);
+				}
+				_ => {
+					return Err(Error::RpcInvalidResponse {
+						reason: format!("batch response item with unknown id: {item}"),
+					})
+				}
+			}
+		}
+
+		ids.into_iter()
+			.map(|id| {
+				let item = by_id.remove(&id).ok_or_else(|| Error::RpcInvalidResponse {
+					reason: format!("no response for the batch call with id {id}"),
+				})?;
+				rpc::into_result(item, id)
+			})
+			.collect()
+	}
+	// endregion: --- JSON-RPC
+
+	// region:    --- Poll
+	/// Start a poll on a GET url, to be completed with `.until(|res| ...)`.
+	/// e.g., `hc.poll("/jobs/123").timeout(Duration::from_secs(10)).until(|res| res.status() == 200).await?`
+	pub fn poll(&self, url: &str) -> Poll<'_> {
+		Poll::new(self, url)
+	}
+	// endregion: --- Poll
+
+	// region:    --- Load Test
+	/// Start a load test running across `concurrency` tasks, to be completed with `.run(|hc| ...)`.
+	/// e.g., `hc.load_test(10).requests(1000).run(|hc| async move { hc.do_get("/api/tickets").await }).await`
+	pub fn load_test(&self, concurrency: usize) -> LoadTest {
+		LoadTest::new(self.clone(), concurrency)
+	}
+	// endregion: --- Load Test
+
+	// region:    --- History
+	/// Keep the last `max` responses of this client (and its clones and sessions), oldest first.
+	/// Default: no history (use `usize::MAX` for an unbounded history)
+	pub fn with_history(mut self, max: usize) -> Self {
+		self.history = Some(Arc::new(Mutex::new(History::new(max))));
+		self
+	}
+
+	/// Return the responses of the history, oldest first (empty if the history is not enabled).
+	pub fn history(&self) -> Vec<Response> {
+		self.with_history_lock(|history| history.responses().cloned().collect())
+			.unwrap_or_default()
+	}
+
+	pub fn last_response(&self) -> Option<Response> {
+		self.with_history_lock(|history| history.responses().last().cloned())
+			.flatten()
+	}
+
+	pub fn clear_history(&self) {
+		self.with_history_lock(|history| history.clear());
+	}
+
+	/// Print all the responses of the history (same as `Response::print`), oldest first.
+	pub async fn print_history(&self) -> Result<()> {
+		print!("{}", self.history_pretty_string());
+		Ok(())
+	}
+
+	/// The pretty printed responses of the history (empty if the history is not enabled).
+	pub(crate) fn history_pretty_string(&self) -> String {
+		self.with_history_lock(|history| history.responses().map(|res| res.to_pretty_string()).collect())
+			.unwrap_or_default()
+	}
+
+	fn with_history_lock<T>(&self, f: impl FnOnce(&mut History) -> T) -> Option<T> {
+		// Note: For now, we will unwrap/panic if fail (as for the cookie store).
+		let mut history = self.history.as_ref()?.lock().unwrap();
+		Some(f(&mut history))
+	}
+	// endregion: --- History
+
 	// region:    --- Cookie
 	pub fn cookie(&self, name: &str) -> Option<Cookie> {
 		let cookie_store = self.cookie_store.lock().unwrap();
@@ -124,42 +577,224 @@ impl Client {
 
 	// region:    --- Client Privates
 
+	/// Deserialize the body of the response for the typed helpers, checking the status per the client policy.
+	fn typed_body<T>(&self, res: Response) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		let res = if self.error_for_status {
+			res.error_for_status()?
+		} else {
+			res
+		};
+		res.json_body_as::<T>()
+	}
+
 	/// Internal implementation for POST, PUT, PATCH
 	async fn do_push(&self, method: Method, url: &str, content: PostContent) -> Result<Response> {
 		let url = self.compose_url(url);
 		if !matches!(method, Method::POST | Method::PUT | Method::PATCH) {
 			return Err(Error::NotSupportedMethodForPush { given_method: method });
 		}
-		let reqwest_res = match content {
-			PostContent::Json(value) => self.reqwest_client.request(method.clone(), &url).json(&value).send().await?,
-			PostContent::Text { content_type, body } => {
-				self.reqwest_client
-					.request(method.clone(), &url)
-					.body(body)
-					.header("content-type", content_type)
-					.send()
-					.await?
-			}
-		};
+		self.execute(method, url, Some(content), HeaderMap::new()).await
+	}
 
-		self.capture_response(method, url, reqwest_res).await
+	/// Send the request with the middleware hooks around, and capture the response.
+	/// Note: With the `tracing` feature, the call (hooks included) runs in its own span.
+	async fn execute(
+		&self,
+		method: Method,
+		url: String,
+		content: Option<PostContent>,
+		headers: HeaderMap,
+	) -> Result<Response> {
+		#[cfg(feature = "tracing")]
+		{
+			use tracing::Instrument;
+			let span = crate::trace::exchange_span(&method, &self.redaction.text(&url));
+			let res = self
+				.execute_hooks(method, url, content, headers)
+				.instrument(span.clone())
+				.await;
+			span.in_scope(|| match &res {
+				Ok(res) => crate::trace::record_response(res),
+				Err(err) => crate::trace::record_error(err),
+			});
+			res
+		}
+		#[cfg(not(feature = "tracing"))]
+		self.execute_hooks(method, url, content, headers).await
 	}
 
-	#[allow(clippy::await_holding_lock)] // ok for testing lib
-	async fn capture_response(
+	async fn execute_hooks(
 		&self,
-		request_method: Method,
+		method: Method,
 		url: String,
-		reqwest_res: reqwest::Response,
+		content: Option<PostContent>,
+		headers: HeaderMap,
 	) -> Result<Response> {
+		let parts = self.before_request(method, url, content, headers).await?;
+		let sent = self
+			.send_with_retry(&parts.method, &parts.url, parts.body.as_ref(), &parts.headers)
+			.await?;
+		let res = self.capture_response(parts.method, parts.url, sent).await?;
+
+		for middleware in self.middlewares.iter() {
+			middleware.after_response(&res).await?;
+		}
+
+		Ok(res)
+	}
+
+	/// Run the `Middleware::before_request` hooks, in order, on the request to be sent.
+	async fn before_request(
+		&self,
+		method: Method,
+		url: String,
+		content: Option<PostContent>,
+		headers: HeaderMap,
+	) -> Result<RequestParts> {
+		let mut all_headers = self.default_headers.clone();
+		all_headers.extend(headers);
+		let mut parts = RequestParts::new(method, url, all_headers, content);
+		for middleware in self.middlewares.iter() {
+			middleware.before_request(&mut parts).await?;
+		}
+		Ok(parts)
+	}
+
+	/// Send the request, and re-send it as long as the retry policy (if any) allows.
+	/// Returns the last reqwest response with the history of the attempts.
+	/// Note: The `headers` are added to (and replace) the ones set for the content.
+	async fn send_with_retry(
+		&self,
+		method: &Method,
+		url: &str,
+		content: Option<&PostContent>,
+		headers: &HeaderMap,
+	) -> Result<Sent> {
+		let mut attempts: Vec<Attempt> = Vec::new();
+
+		loop {
+			let start = Instant::now();
+			let res = self.send_following_redirects(method, url, content, headers).await;
+			let ttfb = start.elapsed();
+
+			let Some(retry_policy) = &self.retry_policy else {
+				return Ok(Sent::new(res?, attempts, ttfb, content));
+			};
+
+			let outcome = match &res {
+				Ok(followed) => AttemptOutcome::Status(followed.res.status()),
+				Err(err) => AttemptOutcome::Error(err),
+			};
+			let mut attempt = Attempt::from(&outcome);
+
+			let attempt_num = attempts.len() as u32 + 1;
+			if attempt_num >= retry_policy.max_attempts() || !retry_policy.should_retry(&outcome) {
+				attempts.push(attempt);
+				return Ok(Sent::new(res?, attempts, ttfb, content));
+			}
+
+			let delay = retry_policy.delay_for(attempt_num, res.as_ref().ok().map(|f| f.res.headers()));
+			attempt.delay = Some(delay);
+			attempts.push(attempt);
+
+			tokio::time::sleep(delay).await;
+		}
+	}
+
+	/// Send the request with the session cookies, storing the response cookies,
+	/// and follow the redirects (up to `MAX_REDIRECTS`, after which the last redirect response is returned).
+	/// Returns the last response with the redirects followed to get it.
+	/// Note: The credentials headers are not sent to another origin, and the content type is not sent
+	///       when a redirect changes the request to a GET.
+	async fn send_following_redirects(
+		&self,
+		method: &Method,
+		url: &str,
+		content: Option<&PostContent>,
+		headers: &HeaderMap,
+	) -> reqwest::Result<Followed> {
+		let mut method = method.clone();
+		let mut url = url.to_string();
+		let mut content = content;
+		let mut headers = headers.clone();
+		let mut redirects: Vec<Redirect> = Vec::new();
+
+		loop {
+			let mut builder = self.build_request(&method, &url, content).headers(headers.clone());
+			if let Some(cookie) = Url::parse(&url).ok().and_then(|u| self.cookie_store.cookies(&u)) {
+				builder = builder.header(COOKIE, cookie);
+			}
+			let req = builder.build()?;
+			let sent_headers = req.headers().clone();
+			let res = self.reqwest_client.execute(req).await?;
+
+			self.cookie_store
+				.set_cookies(&mut res.headers().get_all(SET_COOKIE).iter(), res.url());
+
+			let location = res.headers().get(LOCATION).and_then(|v| v.to_str().ok());
+			let next_url = location.and_then(|l| res.url().join(l).ok());
+			let next_url = next_url.filter(|_| self.follow_redirects && res.status().is_redirection());
+			let Some(next_url) = next_url.filter(|_| redirects.len() < MAX_REDIRECTS) else {
+				return Ok(Followed {
+					res,
+					redirects,
+					method,
+					headers: sent_headers,
+				});
+			};
+			redirects.push(Redirect {
+				status: res.status(),
+				url: res.url().to_string(),
+				location: next_url.to_string(),
+				cookies: res.cookies().map(Cookie::from).collect(),
+			});
+
+			if redirected_as_get(&method, res.status()) {
+				method = Method::GET;
+				content = None;
+				headers.remove(CONTENT_TYPE);
+			}
+			if is_cross_origin(res.url(), &next_url) {
+				remove_sensitive_headers(&mut headers);
+			}
+			url = next_url.to_string();
+		}
+	}
+
+	fn build_request(&self, method: &Method, url: &str, content: Option<&PostContent>) -> reqwest::RequestBuilder {
+		let builder = self.reqwest_client.request(method.clone(), url);
+		match content {
+			None => builder,
+			Some(PostContent::Json(value)) => builder.json(value),
+			Some(PostContent::Text { content_type, body }) => {
+				builder.body(body.clone()).header("content-type", *content_type)
+			}
+		}
+	}
+
+	async fn capture_response(&self, request_method: Method, url: String, sent: Sent) -> Result<Response> {
+		let res = Response::from_reqwest_response(self, request_method, url, sent).await?;
+
+		self.with_history_lock(|history| history.push(res.clone()));
+
+		#[cfg(feature = "openapi")]
+		let res = match &self.openapi {
+			Some(openapi) => openapi.check(res)?,
+			None => res,
+		};
+
+		Ok(res)
+	}
+
+	/// Cookies from the client store
+	pub(crate) fn client_cookies(&self) -> Vec<Cookie> {
 		// Note: For now, we will unwrap/panic if fail.
 		//       Might handle this differently in the future.
 		let cookie_store = self.cookie_store.lock().unwrap();
-
-		// Cookies from the client store
-		let client_cookies: Vec<Cookie> = cookie_store.iter_any().map(|c| from_tower_cookie_deref(c)).collect();
-
-		Response::from_reqwest_response(request_method, url, client_cookies, reqwest_res).await
+		cookie_store.iter_any().map(|c| from_tower_cookie_deref(c)).collect()
 	}
 
 	fn compose_url(&self, url: &str) -> String {
@@ -171,11 +806,62 @@ impl Client {
 	// endregion: --- Client Privates
 }
 
+// region:    --- Sent
+/// The reqwest response (headers received, body not yet read) of the last attempt,
+/// with what has been recorded while sending.
+pub(crate) struct Sent {
+	pub(crate) reqwest_res: reqwest::Response,
+	pub(crate) attempts: Vec<Attempt>,
+	/// Time from sending the request to receiving the response headers.
+	pub(crate) ttfb: Duration,
+	pub(crate) request_body: Option<PostContent>,
+	pub(crate) redirects: Vec<Redirect>,
+	/// Method of the last request sent (GET if a redirect changed it)
+	pub(crate) final_method: Method,
+	/// Headers of the last request sent (without the reqwest client default headers)
+	pub(crate) request_headers: HeaderMap,
+}
+
+impl Sent {
+	fn new(followed: Followed, attempts: Vec<Attempt>, ttfb: Duration, request_body: Option<&PostContent>) -> Self {
+		Sent {
+			reqwest_res: followed.res,
+			redirects: followed.redirects,
+			final_method: followed.method,
+			request_headers: followed.headers,
+			attempts,
+			ttfb,
+			request_body: request_body.cloned(),
+		}
+	}
+}
+
+/// The last response of `send_following_redirects`, with the redirects followed and the last request sent.
+struct Followed {
+	res: reqwest::Response,
+	redirects: Vec<Redirect>,
+	method: Method,
+	headers: HeaderMap,
+}
+// endregion: --- Sent
+
 // region:    --- Post Body
+#[derive(Debug, Clone)]
 pub enum PostContent {
 	Json(Value),
 	Text { body: String, content_type: &'static str },
 }
+impl PostContent {
+	/// The body bytes, as sent.
+	pub(crate) fn to_bytes(&self) -> Vec<u8> {
+		match self {
+			// Note: Same serialization as `reqwest::RequestBuilder::json`.
+			PostContent::Json(value) => serde_json::to_vec(value).unwrap_or_default(),
+			PostContent::Text { body, .. } => body.as_bytes().to_vec(),
+		}
+	}
+}
+
 impl From<Value> for PostContent {
 	fn from(val: Value) -> Self {
 		PostContent::Json(val)
@@ -1,6 +1,6 @@
 use std::time::{Duration, SystemTime};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Cookie {
 	pub name: String,
 	pub value: String,
@@ -0,0 +1,331 @@
+use crate::client::PostContent;
+use crate::redaction::Redaction;
+use crate::{Error, Result};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use reqwest::header::{HeaderMap, COOKIE};
+use reqwest::Method;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Build the curl command of a request, one option per line (joined with ` \`), with the values redacted.
+/// Note: The cookies are passed with `-b`, and the body with `--data-raw`.
+pub(crate) fn to_curl(
+	method: &Method,
+	url: &str,
+	headers: &HeaderMap,
+	body: Option<&PostContent>,
+	redaction: &Redaction,
+) -> String {
+	let mut first = "curl".to_string();
+	if method != Method::GET {
+		first.push_str(&format!(" -X {method}"));
+	}
+	first.push_str(&format!(" {}", shell_quote(&redaction.text(url))));
+	let mut lines = vec![first];
+
+	for (name, value) in headers.iter() {
+		let value = value.to_str().unwrap_or_default();
+		if name == COOKIE {
+			let cookies: Vec<String> = value
+				.split(';')
+				.filter_map(|pair| pair.trim().split_once('='))
+				.map(|(name, value)| format!("{name}={}", redaction.cookie_value(name, value)))
+				.collect();
+			lines.push(format!("-b {}", shell_quote(&cookies.join("; "))));
+		} else {
+			let value = redaction.header_value(name.as_str(), value);
+			lines.push(format!("-H {}", shell_quote(&format!("{name}: {value}"))));
+		}
+	}
+
+	match body {
+		Some(PostContent::Json(value)) => lines.push(format!(
+			"--data-raw {}",
+			shell_quote(&redaction.json(value).to_string())
+		)),
+		Some(PostContent::Text { body, .. }) => {
+			lines.push(format!("--data-raw {}", shell_quote(&redaction.text(body))))
+		}
+		None => (),
+	}
+
+	lines.join(" \\\n  ")
+}
+
+/// Quote the argument for POSIX shells (single quotes, unless only made of safe characters).
+pub(crate) fn shell_quote(arg: &str) -> String {
+	let safe = !arg.is_empty()
+		&& arg
+			.chars()
+			.all(|c| c.is_ascii_alphanumeric() || "-_./:=@,+%".contains(c));
+	if safe {
+		arg.to_string()
+	} else {
+		format!("'{}'", arg.replace('\'', r"'\''"))
+	}
+}
+
+// region:    --- Parser
+/// A request parsed from a curl command line (e.g., copied from the browser devtools), to be sent with
+/// `Client::do_curl_request` (or directly with `Client::do_curl`).
+///
+/// Supported: the url, `-X`, `-H`, `-d`/`--data`/`--data-binary` (with `@file`), `--data-raw`, `--json`,
+/// `-b` (not cookie files), `-u`, `-A`, `-e`, and the quoting of POSIX shells including `$'...'`.
+/// Note: `--compressed`, `-L`, `-s`, `-S`, `-i`, `-v`, `-k` and `-g` are accepted and ignored.
+#[derive(Debug, Clone)]
+pub struct CurlRequest {
+	pub method: Method,
+	pub url: String,
+	/// The headers, in order (the cookies of the `Cookie` headers are in `cookies`)
+	pub headers: Vec<(String, String)>,
+	/// The cookies of `-b` and `Cookie` headers, which are added to the client session
+	pub cookies: Vec<(String, String)>,
+	pub body: Option<String>,
+	/// True for `--json` (the default content type is then `application/json`)
+	pub json: bool,
+}
+
+impl CurlRequest {
+	pub fn parse(command: &str) -> Result<Self> {
+		let mut args = split_command(command)?.into_iter();
+		if args.next().as_deref() != Some("curl") {
+			return Err(parse_error("the command must start with 'curl'"));
+		}
+
+		let mut method: Option<Method> = None;
+		let mut url: Option<String> = None;
+		let mut headers: Vec<(String, String)> = Vec::new();
+		let mut cookies: Vec<(String, String)> = Vec::new();
+		let mut data: Vec<String> = Vec::new();
+		let mut json = false;
+
+		while let Some(arg) = args.next() {
+			let (opt, mut inline_value) = split_option(&arg);
+			let mut value = || {
+				inline_value
+					.take()
+					.or_else(|| args.next())
+					.ok_or_else(|| parse_error(format!("missing value for '{opt}'")))
+			};
+
+			match opt {
+				"-X" | "--request" => {
+					let name = value()?;
+					let m = Method::from_bytes(name.as_bytes())
+						.map_err(|_| parse_error(format!("invalid method '{name}'")))?;
+					method = Some(m);
+				}
+				"-H" | "--header" => {
+					let header = value()?;
+					let (name, value) = header
+						.split_once(':')
+						.ok_or_else(|| parse_error(format!("invalid header '{header}'")))?;
+					let (name, value) = (name.trim(), value.trim());
+					if name.eq_ignore_ascii_case("cookie") {
+						cookies.extend(parse_cookies(value));
+					} else {
+						headers.push((name.to_string(), value.to_string()));
+					}
+				}
+				"-d" | "--data" | "--data-ascii" | "--data-binary" => {
+					let value = value()?;
+					match value.strip_prefix('@') {
+						Some(path) => data.push(std::fs::read_to_string(path)?),
+						None => data.push(value),
+					}
+				}
+				"--data-raw" => data.push(value()?),
+				"--json" => {
+					data.push(value()?);
+					json = true;
+				}
+				"-b" | "--cookie" => {
+					let value = value()?;
+					if !value.contains('=') {
+						return Err(parse_error(format!("cookie files are not supported ('{value}')")));
+					}
+					cookies.extend(parse_cookies(&value));
+				}
+				"-u" | "--user" => {
+					let user = value()?;
+					let (name, password) = user.split_once(':').unwrap_or((&user, ""));
+					let credentials = BASE64_STANDARD.encode(format!("{name}:{password}"));
+					headers.push(("Authorization".to_string(), format!("Basic {credentials}")));
+				}
+				"-A" | "--user-agent" => headers.push(("User-Agent".to_string(), value()?)),
+				"-e" | "--referer" => headers.push(("Referer".to_string(), value()?)),
+				"--url" => url = Some(value()?),
+				"--compressed" | "-L" | "--location" | "-s" | "--silent" | "-S" | "--show-error" | "-i"
+				| "--include" | "-v" | "--verbose" | "-k" | "--insecure" | "-g" | "--globoff" => (),
+				_ if opt.starts_with('-') => return Err(parse_error(format!("unsupported option '{opt}'"))),
+				_ => url = Some(arg.clone()),
+			}
+		}
+
+		let url = url.ok_or_else(|| parse_error("missing url"))?;
+		let method = method.unwrap_or(if data.is_empty() { Method::GET } else { Method::POST });
+		// Note: As curl, the data of multiple `-d` are joined with `&` (and concatenated for `--json`).
+		let body = match (data.is_empty(), json) {
+			(true, _) => None,
+			(false, true) => Some(data.concat()),
+			(false, false) => Some(data.join("&")),
+		};
+
+		Ok(CurlRequest {
+			method,
+			url,
+			headers,
+			cookies,
+			body,
+			json,
+		})
+	}
+
+	/// Return the value of the header (case insensitive).
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers
+			.iter()
+			.find(|(n, _)| n.eq_ignore_ascii_case(name))
+			.map(|(_, v)| v.as_str())
+	}
+}
+
+/// Split `--name=value` and the short options with an attached value (e.g., `-XPOST`).
+fn split_option(arg: &str) -> (&str, Option<String>) {
+	if arg.starts_with("--") {
+		match arg.split_once('=') {
+			Some((opt, value)) => (opt, Some(value.to_string())),
+			None => (arg, None),
+		}
+	} else if arg.len() > 2 && arg.starts_with('-') && arg.is_char_boundary(2) && SHORT_WITH_VALUE.contains(&&arg[..2])
+	{
+		(&arg[..2], Some(arg[2..].to_string()))
+	} else {
+		(arg, None)
+	}
+}
+
+const SHORT_WITH_VALUE: &[&str] = &["-X", "-H", "-d", "-b", "-u", "-A", "-e"];
+
+fn parse_cookies(value: &str) -> Vec<(String, String)> {
+	value
+		.split(';')
+		.filter_map(|pair| pair.trim().split_once('='))
+		.map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+		.collect()
+}
+
+fn parse_error(reason: impl Into<String>) -> Error {
+	Error::CurlParse { reason: reason.into() }
+}
+
+/// Split the command line in arguments per the POSIX shell quoting rules
+/// (single and double quotes, backslash escapes and line continuations, and `$'...'` strings).
+fn split_command(command: &str) -> Result<Vec<String>> {
+	let mut args: Vec<String> = Vec::new();
+	let mut current: Option<String> = None;
+	let mut chars = command.chars().peekable();
+
+	while let Some(c) = chars.next() {
+		match c {
+			c if c.is_whitespace() => {
+				if let Some(arg) = current.take() {
+					args.push(arg);
+				}
+			}
+			'\\' => match chars.next() {
+				Some('\n') => (),
+				Some('\r') if chars.peek() == Some(&'\n') => {
+					chars.next();
+				}
+				Some(c) => current.get_or_insert_with(String::new).push(c),
+				None => (),
+			},
+			'\'' => {
+				let arg = current.get_or_insert_with(String::new);
+				loop {
+					match chars.next() {
+						Some('\'') => break,
+						Some(c) => arg.push(c),
+						None => return Err(parse_error("unterminated single quote")),
+					}
+				}
+			}
+			'"' => {
+				let arg = current.get_or_insert_with(String::new);
+				loop {
+					match chars.next() {
+						Some('"') => break,
+						Some('\\') => match chars.next() {
+							Some(c @ ('"' | '\\' | '$' | '`')) => arg.push(c),
+							Some('\n') => (),
+							Some(c) => {
+								arg.push('\\');
+								arg.push(c);
+							}
+							None => return Err(parse_error("unterminated double quote")),
+						},
+						Some(c) => arg.push(c),
+						None => return Err(parse_error("unterminated double quote")),
+					}
+				}
+			}
+			'$' if chars.peek() == Some(&'\'') => {
+				chars.next();
+				let value = ansi_c_string(&mut chars)?;
+				current.get_or_insert_with(String::new).push_str(&value);
+			}
+			c => current.get_or_insert_with(String::new).push(c),
+		}
+	}
+	if let Some(arg) = current {
+		args.push(arg);
+	}
+
+	Ok(args)
+}
+
+/// Read the rest of a `$'...'` string, with its backslash escapes (the `\xHH` bytes are decoded as utf-8).
+fn ansi_c_string(chars: &mut Peekable<Chars>) -> Result<String> {
+	let mut bytes: Vec<u8> = Vec::new();
+	let push_char = |bytes: &mut Vec<u8>, c: char| bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+
+	loop {
+		match chars.next() {
+			Some('\'') => break,
+			Some('\\') => match chars.next() {
+				Some('n') => bytes.push(b'\n'),
+				Some('t') => bytes.push(b'\t'),
+				Some('r') => bytes.push(b'\r'),
+				Some('a') => bytes.push(0x07),
+				Some('b') => bytes.push(0x08),
+				Some('e' | 'E') => bytes.push(0x1b),
+				Some('f') => bytes.push(0x0c),
+				Some('v') => bytes.push(0x0b),
+				Some('x') => bytes.push(read_hex(chars, 2)? as u8),
+				Some('u') => push_char(&mut bytes, char::from_u32(read_hex(chars, 4)?).unwrap_or('\u{fffd}')),
+				Some('U') => push_char(&mut bytes, char::from_u32(read_hex(chars, 8)?).unwrap_or('\u{fffd}')),
+				Some(c @ ('\\' | '\'' | '"' | '?')) => bytes.push(c as u8),
+				Some(c) => {
+					bytes.push(b'\\');
+					push_char(&mut bytes, c);
+				}
+				None => return Err(parse_error("unterminated $'...' string")),
+			},
+			Some(c) => push_char(&mut bytes, c),
+			None => return Err(parse_error("unterminated $'...' string")),
+		}
+	}
+
+	Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Read up to `max` hex digits.
+fn read_hex(chars: &mut Peekable<Chars>, max: usize) -> Result<u32> {
+	let mut digits = String::new();
+	while digits.len() < max && chars.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+		digits.extend(chars.next());
+	}
+	u32::from_str_radix(&digits, 16).map_err(|_| parse_error("invalid hex escape in $'...' string"))
+}
+// endregion: --- Parser
@@ -1,4 +1,9 @@
-use reqwest::Method;
+use crate::graphql::{format_errors, GraphQLError};
+use crate::rpc::RpcError;
+use crate::Response;
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+use std::time::Duration;
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -14,6 +19,115 @@ pub enum Error {
 	#[error("Not Json value at json pointer: {json_pointer}")]
 	NoJsonValueFound { json_pointer: String },
 
+	#[error(
+		"Cannot deserialize json {} as {type_name} for {request}\n  at path '{path}': {source}\n  json: {snippet}",
+		if json_pointer.is_empty() { "body".to_string() } else { format!("value at '{json_pointer}'") }
+	)]
+	JsonDeserialize {
+		/// The request method and (redacted) url (e.g., `GET http://localhost:8080/api/orders/1`)
+		request: String,
+		json_pointer: String,
+		type_name: &'static str,
+		/// Path of the failing field, relative to the json pointer (e.g., `items[2].price`)
+		path: String,
+		/// Truncated (and redacted) json value
+		snippet: String,
+		source: serde_json::Error,
+	},
+
+	#[error("Invalid header: {reason}")]
+	InvalidHeader { reason: String },
+
+	#[error("Cannot parse curl command: {reason}")]
+	CurlParse { reason: String },
+
+	#[error("HTTP status {status} for {} {}\n{response}", response.request_method(), response.redacted_url())]
+	HttpStatus {
+		status: StatusCode,
+		response: Box<Response>,
+	},
+
+	#[error("Response status {status} is not an error status")]
+	NotAnErrorResponse { status: StatusCode },
+
+	#[error("Poll timeout for {url} after {calls} calls ({elapsed:?}). Last status: {}", last_response.status())]
+	PollTimeout {
+		/// The (redacted) url polled, with the client base url
+		url: String,
+		calls: u32,
+		elapsed: Duration,
+		last_response: Box<Response>,
+	},
+
+	#[error("Elapsed time above limit for {request_method} {request_url}: {elapsed:?} (limit: {limit:?})")]
+	ElapsedAboveLimit {
+		request_method: Method,
+		request_url: String,
+		elapsed: Duration,
+		limit: Duration,
+	},
+
+	#[error("GraphQL response has errors:\n{}", format_errors(errors, query))]
+	GraphQLErrors { query: String, errors: Vec<GraphQLError> },
+
+	#[error("JSON-RPC error: {0}")]
+	Rpc(RpcError),
+
+	#[error("JSON-RPC response id mismatch. Expected: {expected}, Actual: {actual}")]
+	RpcIdMismatch { expected: u64, actual: Value },
+
+	#[error("Invalid JSON-RPC response: {reason}")]
+	RpcInvalidResponse { reason: String },
+
+	#[error("No Server-Sent Event received within {within:?}")]
+	SseTimeout { within: Duration },
+
+	#[error("Server-Sent Events stream ended")]
+	SseStreamEnded,
+
+	#[cfg(feature = "json-schema")]
+	#[error("Invalid JSON Schema: {reason}")]
+	JsonSchemaInvalid { reason: String },
+
+	#[cfg(feature = "json-schema")]
+	#[error(
+		"JSON Schema validation failed for the response body of {request}:\n{}",
+		crate::json_schema::format_violations(violations)
+	)]
+	JsonSchemaViolations {
+		/// The request method and (redacted) url
+		request: String,
+		violations: Vec<crate::json_schema::JsonSchemaViolation>,
+	},
+
+	#[cfg(feature = "openapi")]
+	#[error("Invalid OpenAPI document: {reason}")]
+	OpenApiSpec { reason: String },
+
+	#[cfg(feature = "openapi")]
+	#[error(
+		"OpenAPI contract violations for {} {}:\n{}\n{response}",
+		response.request_method(),
+		response.redacted_url(),
+		crate::openapi::format_violations(violations)
+	)]
+	OpenApiViolations {
+		violations: Vec<crate::openapi::OpenApiViolation>,
+		response: Box<Response>,
+	},
+
+	#[cfg(feature = "ws")]
+	#[error("No WebSocket message received within {within:?}")]
+	WsTimeout { within: Duration },
+
+	#[cfg(feature = "ws")]
+	#[error("WebSocket closed")]
+	WsClosed,
+
+	#[cfg(feature = "ws")]
+	#[error(transparent)]
+	Ws(#[from] tokio_tungstenite::tungstenite::Error),
+
 	#[error(transparent)]
 	IO(#[from] std::io::Error),
 
@@ -22,4 +136,11 @@ pub enum Error {
 
 	#[error(transparent)]
 	SerdeJson(#[from] serde_json::Error),
+
+	#[error(transparent)]
+	Regex(#[from] regex::Error),
+
+	#[cfg(feature = "openapi")]
+	#[error(transparent)]
+	SerdeYaml(#[from] serde_yaml::Error),
 }
@@ -0,0 +1,119 @@
+use crate::{Error, Response, Result};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::fmt;
+
+/// Response of `Client::graphql`, wrapping the http `Response` with the query that was sent.
+#[derive(Debug)]
+pub struct GraphQLResponse {
+	query: String,
+	variables: Value,
+	response: Response,
+}
+
+/// One GraphQL error from the `errors` of the response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GraphQLError {
+	pub message: String,
+	#[serde(default)]
+	pub locations: Vec<GraphQLLocation>,
+	#[serde(default)]
+	pub path: Vec<Value>,
+	pub extensions: Option<Value>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct GraphQLLocation {
+	pub line: u32,
+	pub column: u32,
+}
+
+impl GraphQLResponse {
+	pub(crate) fn new(query: String, variables: Value, response: Response) -> Self {
+		GraphQLResponse {
+			query,
+			variables,
+			response,
+		}
+	}
+
+	pub fn query(&self) -> &str {
+		&self.query
+	}
+
+	pub fn variables(&self) -> &Value {
+		&self.variables
+	}
+
+	/// The underlying http response (e.g., for `print()`).
+	pub fn response(&self) -> &Response {
+		&self.response
+	}
+
+	pub fn into_response(self) -> Response {
+		self.response
+	}
+
+	/// Deserialize the `data` of the response.
+	pub fn data_as<T>(&self) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		self.response.json_value::<T>("/data")
+	}
+
+	/// Deserialize the value at the json pointer relative to `data` (e.g., `/user/name` for `/data/user/name`).
+	pub fn data_value<T>(&self, pointer: &str) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		self.response.json_value::<T>(&format!("/data{pointer}"))
+	}
+
+	/// Return the GraphQL errors of the response (empty if none).
+	pub fn errors(&self) -> Result<Vec<GraphQLError>> {
+		let body = self.response.json_body()?;
+		match body.get("errors") {
+			None | Some(Value::Null) => Ok(Vec::new()),
+			Some(errors) => Ok(serde_json::from_value::<Vec<GraphQLError>>(errors.clone())?),
+		}
+	}
+
+	/// Return an `Error::GraphQLErrors` (with the errors and the query) if the response has errors.
+	pub fn expect_no_errors(&self) -> Result<&Self> {
+		let errors = self.errors()?;
+		if errors.is_empty() {
+			Ok(self)
+		} else {
+			Err(Error::GraphQLErrors {
+				query: self.query.clone(),
+				errors,
+			})
+		}
+	}
+}
+
+impl fmt::Display for GraphQLError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.message)?;
+		if !self.path.is_empty() {
+			let path: Vec<String> = self
+				.path
+				.iter()
+				.map(|p| p.as_str().map(String::from).unwrap_or_else(|| p.to_string()))
+				.collect();
+			write!(f, " (path: {})", path.join("."))?;
+		}
+		for loc in self.locations.iter() {
+			write!(f, " (at {}:{})", loc.line, loc.column)?;
+		}
+		Ok(())
+	}
+}
+
+/// Format the errors and query for `Error::GraphQLErrors`.
+pub(crate) fn format_errors(errors: &[GraphQLError], query: &str) -> String {
+	let errors: Vec<String> = errors.iter().map(|e| format!("  - {e}")).collect();
+	format!("{}\n  Query:\n{}", errors.join("\n"), query.trim())
+}
@@ -0,0 +1,37 @@
+use crate::Response;
+use std::collections::VecDeque;
+
+/// The last responses captured by a client (see `Client::with_history`).
+pub(crate) struct History {
+	max: usize,
+	responses: VecDeque<Response>,
+}
+
+impl History {
+	pub(crate) fn new(max: usize) -> Self {
+		History {
+			max,
+			// Note: Not `with_capacity(max)`, as the max can be large (e.g., `usize::MAX` for unbounded).
+			responses: VecDeque::new(),
+		}
+	}
+
+	/// Add the response, dropping the oldest one if the history is full.
+	pub(crate) fn push(&mut self, res: Response) {
+		if self.max == 0 {
+			return;
+		}
+		if self.responses.len() == self.max {
+			self.responses.pop_front();
+		}
+		self.responses.push_back(res);
+	}
+
+	pub(crate) fn clear(&mut self) {
+		self.responses.clear();
+	}
+
+	pub(crate) fn responses(&self) -> impl Iterator<Item = &Response> {
+		self.responses.iter()
+	}
+}
@@ -0,0 +1,66 @@
+use crate::{Error, Result};
+use serde_json::Value;
+use std::fmt;
+
+/// Max number of chars of the offending json printed for a violation.
+const INSTANCE_MAX_LEN: usize = 300;
+
+/// One JSON Schema validation error of a response body (see `Response::expect_json_schema`).
+#[derive(Debug, Clone)]
+pub struct JsonSchemaViolation {
+	/// Json pointer of the offending value in the body (empty for the body itself)
+	pub instance_path: String,
+	/// Json pointer of the failing keyword in the schema (e.g., `/properties/id/type`)
+	pub schema_path: String,
+	/// The error message (the offending value is masked, see `instance`)
+	pub message: String,
+	/// The offending value (redacted)
+	pub instance: Value,
+}
+
+impl fmt::Display for JsonSchemaViolation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let instance = serde_json::to_string_pretty(&self.instance).unwrap_or_default();
+		let instance = match instance.char_indices().nth(INSTANCE_MAX_LEN) {
+			Some((idx, _)) => format!("{}...", &instance[..idx]),
+			None => instance,
+		};
+		write!(
+			f,
+			"at '{}' (schema '{}'): {}\n    {}",
+			self.instance_path,
+			self.schema_path,
+			self.message,
+			instance.replace('\n', "\n    ")
+		)
+	}
+}
+
+/// Format the violations for `Error::JsonSchemaViolations`.
+pub(crate) fn format_violations(violations: &[JsonSchemaViolation]) -> String {
+	let violations: Vec<String> = violations.iter().map(|v| format!("  - {v}")).collect();
+	violations.join("\n")
+}
+
+/// Validate the json against the schema (draft from the schema `$schema`, 2020-12 by default),
+/// returning all the violations, with their instance taken from `redacted` (same json, redacted).
+pub(crate) fn validate(schema: &Value, json: &Value, redacted: &Value) -> Result<Vec<JsonSchemaViolation>> {
+	let validator = jsonschema::validator_for(schema).map_err(|err| Error::JsonSchemaInvalid {
+		reason: err.to_string(),
+	})?;
+
+	let violations = validator
+		.iter_errors(json)
+		.map(|err| {
+			let instance_path = err.instance_path().to_string();
+			JsonSchemaViolation {
+				instance: redacted.pointer(&instance_path).cloned().unwrap_or(Value::Null),
+				schema_path: err.schema_path().to_string(),
+				message: err.masked().to_string(),
+				instance_path,
+			}
+		})
+		.collect();
+
+	Ok(violations)
+}
@@ -1,7 +1,33 @@
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod client;
 mod cookie;
+mod curl;
 mod error;
+mod graphql;
+mod history;
+#[cfg(feature = "json-schema")]
+mod json_schema;
+mod load;
+mod middleware;
+#[cfg(feature = "openapi")]
+mod openapi;
+mod poll;
+mod print_options;
+mod problem;
+mod redaction;
 mod response;
+mod retry;
+mod rpc;
+mod sse;
+mod stream;
+#[cfg(feature = "tracing")]
+mod trace;
+#[cfg(feature = "ws")]
+mod ws;
 
 // public re-exports
 pub type Result<T> = std::result::Result<T, error::Error>;
@@ -9,5 +35,27 @@ pub use crate::client::new_client;
 pub use crate::client::new_client_with_reqwest;
 pub use crate::client::Client;
 pub use crate::cookie::Cookie;
+pub use crate::curl::CurlRequest;
 pub use crate::error::Error;
-pub use crate::response::Response;
+pub use crate::graphql::{GraphQLError, GraphQLLocation, GraphQLResponse};
+#[cfg(feature = "json-schema")]
+pub use crate::json_schema::JsonSchemaViolation;
+pub use crate::load::{LoadReport, LoadTest};
+pub use crate::middleware::{Middleware, RequestIdMiddleware, RequestParts, TimingLogMiddleware};
+#[cfg(feature = "openapi")]
+pub use crate::openapi::{OpenApi, OpenApiViolation};
+pub use crate::poll::Poll;
+pub use crate::print_options::PrintOptions;
+pub use crate::problem::ProblemDetails;
+pub use crate::redaction::Redaction;
+pub use crate::response::{Redirect, Response, Timing};
+pub use crate::retry::{Attempt, AttemptOutcome, RetryPolicy};
+pub use crate::rpc::{RpcError, RpcResult};
+pub use crate::sse::{SseEvent, SseStream};
+pub use crate::stream::StreamResponse;
+#[cfg(feature = "tracing")]
+pub use crate::trace::TraceparentMiddleware;
+#[cfg(feature = "ws")]
+pub use crate::ws::{WsClient, WsDirection, WsTranscriptEntry};
+#[cfg(feature = "macros")]
+pub use httpc_test_macros::test;
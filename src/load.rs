@@ -0,0 +1,271 @@
+use crate::response::format_duration;
+use crate::{Client, Response, Result};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[allow(unused)]
+#[cfg(feature = "color-output")]
+use colored::*;
+
+/// Load test builder returned by `Client::load_test(concurrency)`.
+///
+/// Runs a closure (given a clone of the client, sharing its cookie store)
+/// across `concurrency` tokio tasks, until `requests` runs are done or `duration` is reached.
+///
+/// Default: 100 requests, no duration limit.
+pub struct LoadTest {
+	client: Client,
+	concurrency: usize,
+	requests: Option<usize>,
+	duration: Option<Duration>,
+}
+
+impl LoadTest {
+	pub(crate) fn new(client: Client, concurrency: usize) -> Self {
+		LoadTest {
+			client,
+			concurrency: concurrency.max(1),
+			requests: None,
+			duration: None,
+		}
+	}
+
+	/// Total number of closure runs (across all tasks).
+	pub fn requests(mut self, requests: usize) -> Self {
+		self.requests = Some(requests);
+		self
+	}
+
+	/// Maximum duration of the load test. No new run is started after this duration.
+	/// Note: When set without `requests`, runs are not limited in number.
+	pub fn duration(mut self, duration: Duration) -> Self {
+		self.duration = Some(duration);
+		self
+	}
+
+	/// Run the load test and return its report.
+	pub async fn run<F, Fut>(self, f: F) -> LoadReport
+	where
+		F: Fn(Client) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<Response>> + Send + 'static,
+	{
+		let max_requests = match (self.requests, self.duration) {
+			(Some(requests), _) => requests,
+			(None, Some(_)) => usize::MAX,
+			(None, None) => 100,
+		};
+
+		let f = Arc::new(f);
+		let counter = Arc::new(AtomicUsize::new(0));
+		let start = Instant::now();
+
+		let handles: Vec<_> = (0..self.concurrency)
+			.map(|_| {
+				let f = f.clone();
+				let counter = counter.clone();
+				let client = self.client.clone();
+				let duration = self.duration;
+				tokio::spawn(async move {
+					let mut samples: Vec<Sample> = Vec::new();
+					while counter.fetch_add(1, Ordering::SeqCst) < max_requests {
+						if duration.is_some_and(|d| start.elapsed() >= d) {
+							break;
+						}
+						let run_start = Instant::now();
+						let outcome = f(client.clone()).await.map(|res| res.status().as_u16());
+						samples.push(Sample {
+							outcome: outcome.map_err(|err| err.to_string()),
+							latency: run_start.elapsed(),
+						});
+					}
+					samples
+				})
+			})
+			.collect();
+
+		let mut report = LoadReport {
+			concurrency: self.concurrency,
+			..Default::default()
+		};
+		for handle in handles {
+			match handle.await {
+				Ok(samples) => samples.into_iter().for_each(|s| report.add(s)),
+				Err(err) => *report.errors.entry(format!("task failed: {err}")).or_default() += 1,
+			}
+		}
+		report.elapsed = start.elapsed();
+		report.latencies.sort();
+
+		report
+	}
+}
+
+struct Sample {
+	/// The status code, or the error message
+	outcome: std::result::Result<u16, String>,
+	latency: Duration,
+}
+
+/// Summary of a `LoadTest` run.
+#[derive(Debug, Default)]
+pub struct LoadReport {
+	concurrency: usize,
+	elapsed: Duration,
+	/// Sorted latencies of all runs (including the failed ones)
+	latencies: Vec<Duration>,
+	statuses: BTreeMap<u16, usize>,
+	errors: BTreeMap<String, usize>,
+}
+
+impl LoadReport {
+	fn add(&mut self, sample: Sample) {
+		self.latencies.push(sample.latency);
+		match sample.outcome {
+			Ok(status) => *self.statuses.entry(status).or_default() += 1,
+			Err(err) => *self.errors.entry(err).or_default() += 1,
+		}
+	}
+}
+
+// region:    --- Accessors
+impl LoadReport {
+	/// Number of closure runs (successful or not).
+	pub fn total(&self) -> usize {
+		self.latencies.len()
+	}
+
+	/// Number of closure runs that returned an error.
+	pub fn error_count(&self) -> usize {
+		self.errors.values().sum()
+	}
+
+	/// Total duration of the load test.
+	pub fn elapsed(&self) -> Duration {
+		self.elapsed
+	}
+
+	/// Runs per second.
+	pub fn throughput(&self) -> f64 {
+		let secs = self.elapsed.as_secs_f64();
+		if secs > 0. {
+			self.total() as f64 / secs
+		} else {
+			0.
+		}
+	}
+
+	/// Count of responses by status code.
+	pub fn statuses(&self) -> &BTreeMap<u16, usize> {
+		&self.statuses
+	}
+
+	/// Count of errors by error message.
+	pub fn errors(&self) -> &BTreeMap<String, usize> {
+		&self.errors
+	}
+
+	/// Latency at a given percentile (0 to 100), using the nearest-rank method.
+	pub fn percentile(&self, percentile: f64) -> Duration {
+		if self.latencies.is_empty() {
+			return Duration::ZERO;
+		}
+		let rank = (percentile.clamp(0., 100.) / 100. * self.latencies.len() as f64).ceil() as usize;
+		self.latencies[rank.clamp(1, self.latencies.len()) - 1]
+	}
+
+	pub fn p50(&self) -> Duration {
+		self.percentile(50.)
+	}
+
+	pub fn p90(&self) -> Duration {
+		self.percentile(90.)
+	}
+
+	pub fn p99(&self) -> Duration {
+		self.percentile(99.)
+	}
+
+	pub fn max(&self) -> Duration {
+		self.latencies.last().copied().unwrap_or_default()
+	}
+}
+// endregion: --- Accessors
+
+// region:    --- Print Methods
+impl LoadReport {
+	/// NOTE: For now, does not need to be async, but keeping it consistent with `Response::print`.
+	#[cfg(feature = "color-output")]
+	pub async fn print(&self) -> Result<()> {
+		println!();
+		println!(
+			"{}: {} runs, concurrency {} {}",
+			" LOAD      ".bold().on_truecolor(50, 50, 50),
+			self.total(),
+			self.concurrency,
+			format!("({})", format_duration(self.elapsed)).dimmed()
+		);
+		println!(" {:<9} : {:.1} req/s", "Throughput".blue(), self.throughput());
+		println!(
+			" {:<9} : p50 {}, p90 {}, p99 {}, max {}",
+			"Latency".blue(),
+			format_duration(self.p50()),
+			format_duration(self.p90()),
+			format_duration(self.p99()),
+			format_duration(self.max())
+		);
+
+		println!(" {:<9} :", "Statuses".blue());
+		for (status, count) in self.statuses.iter() {
+			println!("    {}: {}", status.to_string().yellow(), count);
+		}
+
+		if !self.errors.is_empty() {
+			println!(" {:<9} :", "Errors".red());
+			for (err, count) in self.errors.iter() {
+				println!("    {}: {}", err.yellow(), count);
+			}
+		}
+
+		println!("\n");
+		Ok(())
+	}
+
+	#[cfg(not(feature = "color-output"))]
+	pub async fn print(&self) -> Result<()> {
+		println!();
+		println!(
+			"=== Load test: {} runs, concurrency {} ({})",
+			self.total(),
+			self.concurrency,
+			format_duration(self.elapsed)
+		);
+		println!("=> {:<15}: {:.1} req/s", "Throughput", self.throughput());
+		println!(
+			"=> {:<15}: p50 {}, p90 {}, p99 {}, max {}",
+			"Latency",
+			format_duration(self.p50()),
+			format_duration(self.p90()),
+			format_duration(self.p99()),
+			format_duration(self.max())
+		);
+
+		println!("=> {:<15}:", "Statuses");
+		for (status, count) in self.statuses.iter() {
+			println!("   {}: {}", status, count);
+		}
+
+		if !self.errors.is_empty() {
+			println!("=> {:<15}:", "Errors");
+			for (err, count) in self.errors.iter() {
+				println!("   {}: {}", err, count);
+			}
+		}
+
+		println!("===\n");
+		Ok(())
+	}
+}
+// endregion: --- Print Methods
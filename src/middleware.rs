@@ -0,0 +1,144 @@
+use crate::client::PostContent;
+use crate::response::format_duration;
+use crate::{Error, Response, Result};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::Method;
+
+/// Hooks run for each `do_...` call of the client (and the helpers using them), in the order they were added
+/// with `Client::with_middleware`. An error of a hook is returned as the error of the call.
+///
+/// Note: The hooks run once per call (not per retry attempt or redirect).
+///       Implement it with `#[async_trait::async_trait]`.
+#[async_trait]
+pub trait Middleware: Send + Sync {
+	/// Called before sending the request, which can be modified (e.g., to add a signature header).
+	async fn before_request(&self, _parts: &mut RequestParts) -> Result<()> {
+		Ok(())
+	}
+
+	/// Called with the response (before the typed helpers check the status or deserialize the body).
+	async fn after_response(&self, _res: &Response) -> Result<()> {
+		Ok(())
+	}
+}
+
+/// The request to be sent, given to `Middleware::before_request`.
+#[derive(Debug, Clone)]
+pub struct RequestParts {
+	pub method: Method,
+	/// The full url (with the client base url)
+	pub url: String,
+	/// Headers added to the request (the content type of the body is added when sent, if not set here)
+	pub headers: HeaderMap,
+	pub(crate) body: Option<PostContent>,
+}
+
+impl RequestParts {
+	pub(crate) fn new(method: Method, url: String, headers: HeaderMap, body: Option<PostContent>) -> Self {
+		RequestParts {
+			method,
+			url,
+			headers,
+			body,
+		}
+	}
+
+	/// The body bytes as they will be sent (e.g., to sign them).
+	pub fn body_bytes(&self) -> Option<Vec<u8>> {
+		self.body.as_ref().map(PostContent::to_bytes)
+	}
+
+	/// Replace the body with a json value.
+	pub fn set_json_body(&mut self, value: serde_json::Value) {
+		self.body = Some(PostContent::Json(value));
+	}
+
+	/// Replace the body with a text (e.g., `application/x-www-form-urlencoded` form).
+	pub fn set_text_body(&mut self, body: impl Into<String>, content_type: &'static str) {
+		self.body = Some(PostContent::Text {
+			body: body.into(),
+			content_type,
+		});
+	}
+
+	/// Set (replace) a header, or return an `Error::InvalidHeader` if the name or value is not valid.
+	pub fn set_header(&mut self, name: &str, value: &str) -> Result<()> {
+		let name = header_name(name)?;
+		let value = HeaderValue::from_str(value).map_err(|err| Error::InvalidHeader {
+			reason: format!("invalid value for header '{name}': {err}"),
+		})?;
+		self.headers.insert(name, value);
+		Ok(())
+	}
+}
+
+fn header_name(name: &str) -> Result<HeaderName> {
+	HeaderName::from_bytes(name.as_bytes()).map_err(|err| Error::InvalidHeader {
+		reason: format!("invalid header name '{name}': {err}"),
+	})
+}
+
+// region:    --- Built-ins
+/// Middleware injecting a new random request id header in each request (default header `x-request-id`).
+#[derive(Debug, Clone)]
+pub struct RequestIdMiddleware {
+	header_name: HeaderName,
+}
+
+impl Default for RequestIdMiddleware {
+	fn default() -> Self {
+		RequestIdMiddleware {
+			header_name: HeaderName::from_static("x-request-id"),
+		}
+	}
+}
+
+impl RequestIdMiddleware {
+	/// Set the header name, or return an `Error::InvalidHeader` if not a valid header name.
+	pub fn header_name(mut self, name: &str) -> Result<Self> {
+		self.header_name = header_name(name)?;
+		Ok(self)
+	}
+}
+
+#[async_trait]
+impl Middleware for RequestIdMiddleware {
+	async fn before_request(&self, parts: &mut RequestParts) -> Result<()> {
+		// Random (version 4) uuid.
+		let id = fastrand::u128(..) & !(0xF000 << 64) & !(0xC << 60) | (0x4000 << 64) | (0x8 << 60);
+		let id = format!("{id:032x}");
+		let id = format!(
+			"{}-{}-{}-{}-{}",
+			&id[..8],
+			&id[8..12],
+			&id[12..16],
+			&id[16..20],
+			&id[20..]
+		);
+		// Note: Cannot fail, as the id is made of hex digits and dashes.
+		parts
+			.headers
+			.insert(self.header_name.clone(), HeaderValue::from_str(&id).unwrap());
+		Ok(())
+	}
+}
+
+/// Middleware printing one line per call with the method, (redacted) url, status, and elapsed time.
+#[derive(Debug, Clone, Default)]
+pub struct TimingLogMiddleware;
+
+#[async_trait]
+impl Middleware for TimingLogMiddleware {
+	async fn after_response(&self, res: &Response) -> Result<()> {
+		println!(
+			"{} {} -> {} ({})",
+			res.request_method(),
+			res.redacted_url(),
+			res.status(),
+			format_duration(res.elapsed())
+		);
+		Ok(())
+	}
+}
+// endregion: --- Built-ins
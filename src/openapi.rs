@@ -0,0 +1,339 @@
+use crate::{Error, Response, Result};
+use jsonschema::{Draft, Validator};
+use reqwest::Url;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// Max number of chained `$ref` followed when resolving a path item, response or media type.
+const MAX_REF_DEPTH: usize = 16;
+
+/// OpenAPI 3 document, used to validate the responses against the documented operations
+/// (see `Client::with_openapi`, or `validate` to check a response explicitly).
+///
+/// Note: The schemas are validated as JSON Schema draft 2020-12 for OpenAPI 3.1, and draft 4 for OpenAPI 3.0
+///       (with `nullable: true` rewritten as allowing `null`, the other OpenAPI 3.0 keywords being ignored).
+pub struct OpenApi {
+	doc: Value,
+	draft: Draft,
+	/// Path prefixes of the `servers` urls (e.g., `/api/v1`), with the empty prefix last
+	base_paths: Vec<String>,
+	/// Compiled response schema validators, by schema location
+	validators: Mutex<HashMap<String, Arc<Validator>>>,
+}
+
+/// One difference between a response and the OpenAPI document.
+#[derive(Debug, Clone)]
+pub struct OpenApiViolation {
+	/// Json pointer in the response body (None when the violation is not about the body content)
+	pub instance_path: Option<String>,
+	pub message: String,
+}
+
+impl fmt::Display for OpenApiViolation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.instance_path {
+			Some(path) => write!(f, "body '{path}': {}", self.message),
+			None => f.write_str(&self.message),
+		}
+	}
+}
+
+/// Format the violations for `Error::OpenApiViolations`.
+pub(crate) fn format_violations(violations: &[OpenApiViolation]) -> String {
+	let violations: Vec<String> = violations.iter().map(|v| format!("  - {v}")).collect();
+	violations.join("\n")
+}
+
+// region:    --- Load
+impl OpenApi {
+	/// Load the document from a `.json`, `.yaml` or `.yml` file.
+	pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+		let path = path.as_ref();
+		let content = std::fs::read_to_string(path)?;
+		match path.extension().and_then(|e| e.to_str()) {
+			Some("json") => Self::from_json(&content),
+			_ => Self::from_yaml(&content),
+		}
+	}
+
+	pub fn from_json(content: &str) -> Result<Self> {
+		Self::from_value(serde_json::from_str::<Value>(content)?)
+	}
+
+	pub fn from_yaml(content: &str) -> Result<Self> {
+		Self::from_value(serde_yaml::from_str::<Value>(content)?)
+	}
+
+	pub fn from_value(doc: Value) -> Result<Self> {
+		let version = doc.get("openapi").and_then(Value::as_str).unwrap_or_default();
+		let draft = if version.starts_with("3.0") {
+			Draft::Draft4
+		} else if version.starts_with("3.") {
+			Draft::Draft202012
+		} else {
+			return Err(Error::OpenApiSpec {
+				reason: format!("unsupported 'openapi' version '{version}' (only OpenAPI 3 is supported)"),
+			});
+		};
+		if !doc.get("paths").is_some_and(Value::is_object) {
+			return Err(Error::OpenApiSpec {
+				reason: "missing 'paths' object".to_string(),
+			});
+		}
+
+		let mut base_paths: Vec<String> = doc
+			.get("servers")
+			.and_then(Value::as_array)
+			.into_iter()
+			.flatten()
+			.filter_map(server_base_path)
+			.filter(|p| !p.is_empty())
+			.collect();
+		base_paths.push(String::new());
+
+		Ok(OpenApi {
+			doc,
+			draft,
+			base_paths,
+			validators: Mutex::new(HashMap::new()),
+		})
+	}
+}
+
+/// Path of a server url, with the variables replaced by their default (e.g., `https://{host}/api/v1` -> `/api/v1`).
+fn server_base_path(server: &Value) -> Option<String> {
+	let mut url = server.get("url")?.as_str()?.to_string();
+	if let Some(variables) = server.get("variables").and_then(Value::as_object) {
+		for (name, var) in variables {
+			let default = var.get("default").and_then(Value::as_str).unwrap_or_default();
+			url = url.replace(&format!("{{{name}}}"), default);
+		}
+	}
+	let path = match Url::parse(&url) {
+		Ok(url) => url.path().to_string(),
+		Err(_) => url,
+	};
+	Some(path.trim_end_matches('/').to_string())
+}
+// endregion: --- Load
+
+// region:    --- Validate
+impl OpenApi {
+	/// Validate the response against the operation matching its method and path
+	/// (the final ones when redirects were followed). Returns the violations (empty if conform).
+	pub fn validate(&self, res: &Response) -> Vec<OpenApiViolation> {
+		match self.validate_response(res) {
+			Ok(violations) => violations,
+			Err(message) => vec![OpenApiViolation {
+				instance_path: None,
+				message,
+			}],
+		}
+	}
+
+	/// Return an `Error::OpenApiViolations` (with the response) if the response does not conform.
+	pub(crate) fn check(&self, res: Response) -> Result<Response> {
+		let violations = self.validate(&res);
+		if violations.is_empty() {
+			Ok(res)
+		} else {
+			Err(Error::OpenApiViolations {
+				violations,
+				response: Box::new(res),
+			})
+		}
+	}
+
+	/// Note: Err for the violations preventing further checks (e.g., undocumented path or status).
+	fn validate_response(&self, res: &Response) -> std::result::Result<Vec<OpenApiViolation>, String> {
+		let method = res.final_method();
+		let url = Url::parse(res.final_url()).map_err(|err| format!("invalid url '{}': {err}", res.final_url()))?;
+
+		// -- Operation
+		let (template, path_item) = self
+			.find_path(url.path())
+			.ok_or_else(|| format!("no path matching '{}' in the OpenAPI document", url.path()))?;
+		let method_key = method.as_str().to_lowercase();
+		let operation = path_item
+			.get(&method_key)
+			.ok_or_else(|| format!("no '{method}' operation for path '{template}' in the OpenAPI document"))?;
+
+		// -- Response for the status
+		let status = res.status();
+		let responses = operation.get("responses").and_then(Value::as_object);
+		let status_keys = [
+			status.as_str().to_string(),
+			format!("{}XX", status.as_u16() / 100),
+			"default".to_string(),
+		];
+		let (status_key, response) = status_keys
+			.iter()
+			.find_map(|key| {
+				let response = responses?.iter().find(|(k, _)| k.eq_ignore_ascii_case(key))?.1;
+				Some((key.as_str(), self.resolve(response)))
+			})
+			.ok_or_else(|| format!("status {status} is not documented for '{method} {template}'"))?;
+
+		// -- Content
+		let Some(content) = response.get("content").and_then(Value::as_object) else {
+			return Ok(Vec::new());
+		};
+		let content_type = res.header("content-type").unwrap_or_default();
+		let mime = content_type.split(';').next().unwrap_or_default().trim().to_lowercase();
+		if mime.is_empty() {
+			return Ok(Vec::new());
+		}
+		let (media_key, media) = find_media_type(content, &mime).ok_or_else(|| {
+			format!("content type '{mime}' is not documented for '{method} {template}' status {status_key}")
+		})?;
+
+		// -- Schema
+		let (Some(schema), Ok(body)) = (self.resolve(media).get("schema"), res.json_body()) else {
+			return Ok(Vec::new());
+		};
+		let location = format!("{method_key} {template} {status_key} {media_key}");
+		let validator = self.validator(&location, schema)?;
+		let violations = validator
+			.iter_errors(&body)
+			.map(|err| OpenApiViolation {
+				instance_path: Some(err.instance_path().to_string()),
+				message: err.masked().to_string(),
+			})
+			.collect();
+
+		Ok(violations)
+	}
+
+	/// Return the path template and path item matching the request path.
+	/// Note: The templates with the most literal segments win (e.g., `/users/me` over `/users/{id}`).
+	fn find_path(&self, path: &str) -> Option<(&str, &Value)> {
+		let paths = self.doc.get("paths")?.as_object()?;
+		for base_path in self.base_paths.iter() {
+			let Some(rel_path) = path.strip_prefix(base_path.as_str()) else {
+				continue;
+			};
+			let found = paths
+				.iter()
+				.filter_map(|(template, item)| {
+					path_match(template, rel_path).map(|literals| (literals, template, item))
+				})
+				.max_by_key(|(literals, ..)| *literals);
+			if let Some((_, template, item)) = found {
+				return Some((template.as_str(), self.resolve(item)));
+			}
+		}
+		None
+	}
+
+	/// Follow the local `$ref` of the value (if any).
+	fn resolve<'a>(&'a self, mut value: &'a Value) -> &'a Value {
+		for _ in 0..MAX_REF_DEPTH {
+			let target = value
+				.get("$ref")
+				.and_then(Value::as_str)
+				.and_then(|r| r.strip_prefix('#'))
+				.and_then(|pointer| self.doc.pointer(pointer));
+			match target {
+				Some(target) => value = target,
+				None => break,
+			}
+		}
+		value
+	}
+
+	fn validator(&self, location: &str, schema: &Value) -> std::result::Result<Arc<Validator>, String> {
+		// Note: For now, we will unwrap/panic if fail (as for the cookie store).
+		let mut validators = self.validators.lock().unwrap();
+		if let Some(validator) = validators.get(location) {
+			return Ok(validator.clone());
+		}
+
+		// The schema with the document components, so that the `#/components/...` references resolve.
+		let mut root = json!({
+			"components": self.doc.get("components").cloned().unwrap_or(Value::Null),
+			"allOf": [schema],
+		});
+		if self.draft == Draft::Draft4 {
+			rewrite_nullable(&mut root);
+		}
+		let validator = jsonschema::options()
+			.with_draft(self.draft)
+			.build(&root)
+			.map_err(|err| format!("invalid response schema for '{location}': {err}"))?;
+		let validator = Arc::new(validator);
+		validators.insert(location.to_string(), validator.clone());
+		Ok(validator)
+	}
+}
+
+/// Rewrite the OpenAPI 3.0 `nullable: true` schemas (at any depth) as JSON Schema allowing `null`
+/// (e.g., `{type: string, nullable: true}` -> `{type: [string, "null"]}`).
+fn rewrite_nullable(value: &mut Value) {
+	match value {
+		Value::Object(obj) => {
+			if obj.get("nullable") == Some(&Value::Bool(true)) {
+				obj.remove("nullable");
+				if let Some(Value::Array(values)) = obj.get_mut("enum") {
+					values.push(Value::Null);
+				}
+				match obj.get_mut("type") {
+					Some(Value::String(type_name)) => {
+						let type_name = std::mem::take(type_name);
+						obj.insert("type".to_string(), json!([type_name, "null"]));
+					}
+					Some(Value::Array(types)) => types.push(json!("null")),
+					// e.g., `allOf` or `$ref` without type
+					_ => {
+						let schema = Value::Object(std::mem::take(obj));
+						obj.insert("anyOf".to_string(), json!([schema, {"type": "null"}]));
+					}
+				}
+			}
+			obj.values_mut().for_each(rewrite_nullable);
+		}
+		Value::Array(values) => values.iter_mut().for_each(rewrite_nullable),
+		_ => (),
+	}
+}
+
+/// Return the number of literal segments if the path matches the template (e.g., `/users/{id}`).
+fn path_match(template: &str, path: &str) -> Option<usize> {
+	let template_segs: Vec<&str> = template.trim_end_matches('/').split('/').collect();
+	let path_segs: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+	if template_segs.len() != path_segs.len() {
+		return None;
+	}
+
+	let mut literals = 0;
+	for (t, p) in template_segs.iter().zip(path_segs.iter()) {
+		match (t.find('{'), t.rfind('}')) {
+			// segment with a parameter, possibly with a prefix or suffix (e.g., `{id}.json`)
+			(Some(start), Some(end)) if start < end => {
+				let (prefix, suffix) = (&t[..start], &t[end + 1..]);
+				let matches = p.len() > prefix.len() + suffix.len() && p.starts_with(prefix) && p.ends_with(suffix);
+				if !matches {
+					return None;
+				}
+			}
+			_ if t == p => literals += 1,
+			_ => return None,
+		}
+	}
+	Some(literals)
+}
+
+/// Find the media type of the content for the mime, exact first, then `type/*` and `*/*`.
+fn find_media_type<'a>(content: &'a serde_json::Map<String, Value>, mime: &str) -> Option<(&'a str, &'a Value)> {
+	let type_wildcard = format!("{}/*", mime.split('/').next().unwrap_or_default());
+	let keys = [mime, type_wildcard.as_str(), "*/*"];
+	keys.iter().find_map(|key| {
+		content
+			.iter()
+			.find(|(k, _)| k.eq_ignore_ascii_case(key))
+			.map(|(k, v)| (k.as_str(), v))
+	})
+}
+// endregion: --- Validate
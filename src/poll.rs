@@ -0,0 +1,83 @@
+use crate::{Client, Error, Response, Result};
+use std::time::{Duration, Instant};
+
+/// Poll builder returned by `Client::poll(url)`.
+///
+/// Re-issues a GET on the url until the `until` predicate is satisfied or the timeout is reached.
+///
+/// Default: 500ms interval, no backoff, 30s timeout.
+pub struct Poll<'a> {
+	client: &'a Client,
+	url: String,
+	interval: Duration,
+	max_interval: Duration,
+	backoff: f64,
+	timeout: Duration,
+}
+
+impl<'a> Poll<'a> {
+	pub(crate) fn new(client: &'a Client, url: &str) -> Self {
+		Poll {
+			client,
+			url: url.to_string(),
+			interval: Duration::from_millis(500),
+			max_interval: Duration::from_secs(5),
+			backoff: 1.0,
+			timeout: Duration::from_secs(30),
+		}
+	}
+
+	/// Delay between two calls (the first call is made immediately).
+	pub fn interval(mut self, interval: Duration) -> Self {
+		self.interval = interval;
+		self
+	}
+
+	/// Multiply the interval by `factor` after each call, up to `max_interval`.
+	pub fn backoff(mut self, factor: f64, max_interval: Duration) -> Self {
+		self.backoff = factor.max(1.0);
+		self.max_interval = max_interval;
+		self
+	}
+
+	/// Maximum total time before giving up with an `Error::PollTimeout`.
+	pub fn timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = timeout;
+		self
+	}
+
+	/// Call the url until `predicate` returns true, and return this last response.
+	///
+	/// On timeout, the last response is printed and returned in the `Error::PollTimeout`.
+	pub async fn until<F>(self, predicate: F) -> Result<Response>
+	where
+		F: Fn(&Response) -> bool,
+	{
+		let start = Instant::now();
+		let mut interval = self.interval;
+		let mut calls: u32 = 0;
+
+		loop {
+			let res = self.client.do_get(&self.url).await?;
+			calls += 1;
+
+			if predicate(&res) {
+				return Ok(res);
+			}
+
+			let elapsed = start.elapsed();
+			if elapsed + interval > self.timeout {
+				res.print().await?;
+				return Err(Error::PollTimeout {
+					url: res.redacted_url(),
+					calls,
+					elapsed,
+					last_response: Box::new(res),
+				});
+			}
+
+			tokio::time::sleep(interval).await;
+			interval = interval.mul_f64(self.backoff).min(self.max_interval.max(self.interval));
+		}
+	}
+}
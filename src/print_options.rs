@@ -0,0 +1,190 @@
+use crate::Result;
+use serde_json::{to_string, to_string_pretty, Value};
+
+/// Options for printing a `Response` (`print_with`, `write_to`, `to_pretty_string_with`).
+/// Can be set as the client default with `Client::with_print_options`.
+///
+/// Default: print the headers, cookies, and full pretty body (not the request body).
+#[derive(Debug, Clone)]
+pub struct PrintOptions {
+	pub(crate) request: bool,
+	pub(crate) curl: bool,
+	pub(crate) headers: bool,
+	pub(crate) header_allow: Option<Vec<String>>,
+	pub(crate) header_deny: Vec<String>,
+	pub(crate) cookies: bool,
+	pub(crate) body: bool,
+	pub(crate) body_max_len: Option<usize>,
+	pub(crate) max_array_items: Option<usize>,
+	pub(crate) json_pointer: Option<String>,
+	pub(crate) compact: bool,
+}
+
+impl Default for PrintOptions {
+	fn default() -> Self {
+		PrintOptions {
+			request: false,
+			curl: false,
+			headers: true,
+			header_allow: None,
+			header_deny: Vec::new(),
+			cookies: true,
+			body: true,
+			body_max_len: None,
+			max_array_items: None,
+			json_pointer: None,
+			compact: false,
+		}
+	}
+}
+
+// region:    --- Builder Methods
+impl PrintOptions {
+	/// Print the request body that was sent (default false).
+	pub fn request(mut self, request: bool) -> Self {
+		self.request = request;
+		self
+	}
+
+	/// Print the request as a curl command, to rerun it by hand (default false).
+	pub fn curl(mut self, curl: bool) -> Self {
+		self.curl = curl;
+		self
+	}
+
+	/// Print the response headers (default true).
+	pub fn headers(mut self, headers: bool) -> Self {
+		self.headers = headers;
+		self
+	}
+
+	/// Only print these response headers (case insensitive).
+	pub fn header_allow(mut self, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		self.header_allow = Some(names.into_iter().map(|n| n.into().to_lowercase()).collect());
+		self
+	}
+
+	/// Do not print these response headers (case insensitive).
+	pub fn header_deny(mut self, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		self.header_deny = names.into_iter().map(|n| n.into().to_lowercase()).collect();
+		self
+	}
+
+	/// Print the response and client cookies (default true).
+	pub fn cookies(mut self, cookies: bool) -> Self {
+		self.cookies = cookies;
+		self
+	}
+
+	/// Print the response body (default true).
+	pub fn body(mut self, body: bool) -> Self {
+		self.body = body;
+		self
+	}
+
+	/// Truncate the printed body after `max_len` characters.
+	pub fn body_max_len(mut self, max_len: usize) -> Self {
+		self.body_max_len = Some(max_len);
+		self
+	}
+
+	/// Only print the first `max_items` of each json array (at any depth).
+	pub fn max_array_items(mut self, max_items: usize) -> Self {
+		self.max_array_items = Some(max_items);
+		self
+	}
+
+	/// Only print the json body subtree at this json pointer (e.g., `/data/items`).
+	pub fn json_pointer(mut self, pointer: impl Into<String>) -> Self {
+		self.json_pointer = Some(pointer.into());
+		self
+	}
+
+	/// Print the json body on one line rather than pretty printed (default false).
+	pub fn compact(mut self, compact: bool) -> Self {
+		self.compact = compact;
+		self
+	}
+}
+// endregion: --- Builder Methods
+
+// region:    --- Crate Privates
+impl PrintOptions {
+	pub(crate) fn show_header(&self, name: &str) -> bool {
+		let name = name.to_lowercase();
+		let allowed = self
+			.header_allow
+			.as_ref()
+			.map(|allow| allow.contains(&name))
+			.unwrap_or(true);
+		allowed && !self.header_deny.contains(&name)
+	}
+
+	/// Render a json value per these options.
+	/// Returns the string and whether it is still valid json (i.e., was not truncated).
+	pub(crate) fn render_json(&self, value: &Value) -> Result<(String, bool)> {
+		let value = match &self.json_pointer {
+			Some(pointer) => match value.pointer(pointer) {
+				Some(value) => value,
+				None => return Ok((format!("<no json value at pointer '{pointer}'>"), false)),
+			},
+			None => value,
+		};
+
+		let content = match self.max_array_items {
+			Some(max_items) => self.to_json_string(&truncate_arrays(value, max_items))?,
+			None => self.to_json_string(value)?,
+		};
+
+		Ok(self.truncate(content))
+	}
+
+	/// Render a text body per these options.
+	pub(crate) fn render_text(&self, text: &str) -> String {
+		self.truncate(text.to_string()).0
+	}
+
+	fn to_json_string(&self, value: &Value) -> Result<String> {
+		Ok(if self.compact {
+			to_string(value)?
+		} else {
+			to_string_pretty(value)?
+		})
+	}
+
+	/// Returns the possibly truncated content, and true if it was not truncated.
+	fn truncate(&self, content: String) -> (String, bool) {
+		match self.body_max_len {
+			Some(max_len) if content.chars().count() > max_len => {
+				let total = content.chars().count();
+				let mut content: String = content.chars().take(max_len).collect();
+				content.push_str(&format!("... ({} more chars)", total - max_len));
+				(content, false)
+			}
+			_ => (content, true),
+		}
+	}
+}
+
+fn truncate_arrays(value: &Value, max_items: usize) -> Value {
+	match value {
+		Value::Array(items) => {
+			let mut new_items: Vec<Value> = items
+				.iter()
+				.take(max_items)
+				.map(|v| truncate_arrays(v, max_items))
+				.collect();
+			if items.len() > max_items {
+				new_items.push(Value::String(format!("... ({} more items)", items.len() - max_items)));
+			}
+			Value::Array(new_items)
+		}
+		Value::Object(map) => Value::Object(
+			map.iter()
+				.map(|(k, v)| (k.clone(), truncate_arrays(v, max_items)))
+				.collect(),
+		),
+		_ => value.clone(),
+	}
+}
+// endregion: --- Crate Privates
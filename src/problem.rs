@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// RFC 7807 (RFC 9457) problem details document (`application/problem+json`),
+/// to be used as the error type of `Client::get_result` or `Response::json_error_as`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProblemDetails {
+	/// The problem type URI (`about:blank` when absent)
+	#[serde(rename = "type", default = "about_blank")]
+	pub type_uri: String,
+	pub title: Option<String>,
+	pub status: Option<u16>,
+	pub detail: Option<String>,
+	pub instance: Option<String>,
+	/// The extension members (any other property)
+	#[serde(flatten)]
+	pub extensions: Map<String, Value>,
+}
+
+fn about_blank() -> String {
+	"about:blank".to_string()
+}
@@ -0,0 +1,170 @@
+use crate::Result;
+use regex::{Captures, Regex};
+use serde_json::Value;
+
+const MASK: &str = "***";
+
+/// Redaction rules applied to the printed output (and other renderings) of the responses,
+/// so that secrets do not end up in the CI logs.
+/// Set on the client with `Client::with_redaction`.
+///
+/// Default: mask the `Authorization`, `Proxy-Authorization`, `Cookie`, `Set-Cookie` headers,
+///          all the cookie values, and the common token json fields (e.g., `password`, `access_token`).
+///          Use `Redaction::none()` to print everything verbatim.
+#[derive(Debug, Clone)]
+pub struct Redaction {
+	headers: Vec<String>,
+	all_cookies: bool,
+	cookies: Vec<String>,
+	json_pointers: Vec<String>,
+	json_fields: Vec<String>,
+	patterns: Vec<Regex>,
+}
+
+impl Default for Redaction {
+	fn default() -> Self {
+		Redaction {
+			headers: ["authorization", "proxy-authorization", "cookie", "set-cookie"]
+				.map(String::from)
+				.to_vec(),
+			all_cookies: true,
+			json_fields: [
+				"password",
+				"secret",
+				"token",
+				"access_token",
+				"refresh_token",
+				"id_token",
+				"client_secret",
+				"api_key",
+				"apikey",
+			]
+			.map(String::from)
+			.to_vec(),
+			..Redaction::none()
+		}
+	}
+}
+
+// region:    --- Builder Methods
+impl Redaction {
+	/// No redaction at all.
+	pub fn none() -> Self {
+		Redaction {
+			headers: Vec::new(),
+			all_cookies: false,
+			cookies: Vec::new(),
+			json_pointers: Vec::new(),
+			json_fields: Vec::new(),
+			patterns: Vec::new(),
+		}
+	}
+
+	/// Mask the value of this header (case insensitive).
+	pub fn header(mut self, name: impl Into<String>) -> Self {
+		self.headers.push(name.into().to_lowercase());
+		self
+	}
+
+	/// Mask the value of this cookie.
+	pub fn cookie(mut self, name: impl Into<String>) -> Self {
+		self.cookies.push(name.into());
+		self
+	}
+
+	/// Mask the values of all the cookies (default true, false for `Redaction::none()`).
+	pub fn all_cookies(mut self, all_cookies: bool) -> Self {
+		self.all_cookies = all_cookies;
+		self
+	}
+
+	/// Mask the json value at this json pointer (e.g., `/data/user/ssn`) in the request and response bodies.
+	pub fn json_pointer(mut self, pointer: impl Into<String>) -> Self {
+		self.json_pointers.push(pointer.into());
+		self
+	}
+
+	/// Mask the values of the json properties with this name (case insensitive), at any depth.
+	pub fn json_field(mut self, name: impl Into<String>) -> Self {
+		self.json_fields.push(name.into().to_lowercase());
+		self
+	}
+
+	/// Mask the matches of this regex in urls, header values, and text or json string values.
+	/// If the regex has a capture group, only the first group is masked (e.g., `token=([^&]+)`).
+	pub fn pattern(mut self, regex: &str) -> Result<Self> {
+		self.patterns.push(Regex::new(regex)?);
+		Ok(self)
+	}
+}
+// endregion: --- Builder Methods
+
+// region:    --- Crate Privates
+impl Redaction {
+	pub(crate) fn header_value(&self, name: &str, value: &str) -> String {
+		if self.headers.iter().any(|h| h.eq_ignore_ascii_case(name)) {
+			MASK.to_string()
+		} else {
+			self.text(value)
+		}
+	}
+
+	pub(crate) fn cookie_value(&self, name: &str, value: &str) -> String {
+		if self.all_cookies || self.cookies.iter().any(|c| c == name) {
+			MASK.to_string()
+		} else {
+			self.text(value)
+		}
+	}
+
+	/// Apply the patterns to a text (e.g., url, text body).
+	pub(crate) fn text(&self, text: &str) -> String {
+		let mut text = text.to_string();
+		for pattern in self.patterns.iter() {
+			text = pattern
+				.replace_all(&text, |caps: &Captures| mask_captures(caps))
+				.into_owned();
+		}
+		text
+	}
+
+	/// Return a copy of the json value with the redacted values masked.
+	pub(crate) fn json(&self, value: &Value) -> Value {
+		let mut value = value.clone();
+		for pointer in self.json_pointers.iter() {
+			if let Some(v) = value.pointer_mut(pointer) {
+				*v = Value::String(MASK.to_string());
+			}
+		}
+		self.mask_json_in_place(&mut value);
+		value
+	}
+
+	fn mask_json_in_place(&self, value: &mut Value) {
+		match value {
+			Value::Object(map) => {
+				for (k, v) in map.iter_mut() {
+					if self.json_fields.contains(&k.to_lowercase()) {
+						*v = Value::String(MASK.to_string());
+					} else {
+						self.mask_json_in_place(v);
+					}
+				}
+			}
+			Value::Array(items) => items.iter_mut().for_each(|v| self.mask_json_in_place(v)),
+			Value::String(s) if !self.patterns.is_empty() => *s = self.text(s),
+			_ => (),
+		}
+	}
+}
+
+fn mask_captures(caps: &Captures) -> String {
+	let (Some(full), Some(group)) = (caps.get(0), caps.get(1)) else {
+		return MASK.to_string();
+	};
+	let full_str = full.as_str();
+	let start = group.start() - full.start();
+	let end = group.end() - full.start();
+	format!("{}{MASK}{}", &full_str[..start], &full_str[end..])
+}
+// endregion: --- Crate Privates
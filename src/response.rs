@@ -1,8 +1,16 @@
+use crate::client::{Client, PostContent, Sent};
 use crate::cookie::Cookie;
+use crate::print_options::PrintOptions;
+use crate::redaction::Redaction;
+use crate::retry::Attempt;
 use crate::{Error, Result};
 use reqwest::{Method, StatusCode};
 use serde::de::DeserializeOwned;
-use serde_json::{to_string_pretty, Value};
+use serde_json::Value;
+use std::fmt;
+use std::io::Write;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[allow(unused)]
 #[cfg(feature = "color-output")]
@@ -10,11 +18,16 @@ use colored::*;
 #[allow(unused)]
 #[cfg(feature = "color-output")]
 use colored_json::prelude::*;
+#[cfg(feature = "color-output")]
+use colored_json::{ColoredFormatter, CompactFormatter};
 use reqwest::header::HeaderMap;
 
+#[derive(Clone)]
 pub struct Response {
 	request_method: Method,
 	request_url: String,
+	request_body: Option<PostContent>,
+	session_name: Option<String>,
 
 	status: StatusCode,
 	header_map: HeaderMap,
@@ -24,8 +37,56 @@ pub struct Response {
 	/// Cookies from the response
 	cookies: Vec<Cookie>,
 	body: Body,
+
+	/// Attempts made for this call (empty when the client has no retry policy)
+	attempts: Vec<Attempt>,
+
+	timing: Timing,
+
+	/// Url of the response (different from the request url when redirected)
+	final_url: String,
+	/// Method of the last request (GET when changed by a redirect)
+	final_method: Method,
+	/// Headers of the last request (without the reqwest client default headers)
+	request_headers: HeaderMap,
+	/// Redirects followed to get this response
+	redirects: Vec<Redirect>,
+
+	/// Default print options (from the client)
+	print_options: PrintOptions,
+	/// Redaction rules applied when printing (from the client)
+	redaction: Arc<Redaction>,
+}
+
+/// Timing of the (last attempt of the) http call.
+#[derive(Debug, Clone, Copy)]
+pub struct Timing {
+	/// Time from sending the request to receiving the response status and headers (time to first byte).
+	pub ttfb: Duration,
+	/// Time to download the response body.
+	pub body: Duration,
+}
+
+impl Timing {
+	/// Total elapsed time (ttfb + body download).
+	pub fn total(&self) -> Duration {
+		self.ttfb + self.body
+	}
 }
 
+/// One redirect response followed by the client.
+#[derive(Debug, Clone)]
+pub struct Redirect {
+	pub status: StatusCode,
+	/// Url of the redirect response
+	pub url: String,
+	/// The (absolute) url of the `Location` header, which was requested next
+	pub location: String,
+	/// Cookies set by the redirect response
+	pub cookies: Vec<Cookie>,
+}
+
+#[derive(Debug, Clone)]
 enum Body {
 	Json(Value),
 	Text(String),
@@ -82,18 +143,69 @@ fn format_method(method: &Method) -> String {
 	format!(" {:<10}", method.to_string())
 }
 
+/// Max number of chars of the json snippet of `Error::JsonDeserialize`.
+const JSON_SNIPPET_MAX_LEN: usize = 200;
+
+fn json_snippet(value: &Value) -> String {
+	let json = value.to_string();
+	match json.char_indices().nth(JSON_SNIPPET_MAX_LEN) {
+		Some((idx, _)) => format!("{}... ({} chars)", &json[..idx], json.chars().count()),
+		None => json,
+	}
+}
+
+/// True for `application/json` and the `+json` structured syntax types (e.g., `application/problem+json`).
+fn is_json_content_type(content_type: &str) -> bool {
+	let mime = content_type.split(';').next().unwrap_or_default().trim();
+	mime.starts_with("application/json") || mime.ends_with("+json")
+}
+
+/// Format a duration for display (e.g., `850µs`, `125ms`, `2.35s`).
+pub(crate) fn format_duration(duration: Duration) -> String {
+	let micros = duration.as_micros();
+	if micros < 1_000 {
+		format!("{micros}µs")
+	} else if micros < 10_000_000 {
+		format!("{}ms", duration.as_millis())
+	} else {
+		format!("{:.2}s", duration.as_secs_f64())
+	}
+}
+
+fn format_attempt(attempt: &Attempt) -> String {
+	let outcome = match (&attempt.status, &attempt.error) {
+		(Some(status), _) => format!("{} {}", status.as_str(), status.canonical_reason().unwrap_or_default()),
+		(None, Some(err)) => format!("error: {err}"),
+		(None, None) => "unknown".to_string(),
+	};
+	match attempt.delay {
+		Some(delay) => format!("{outcome} (retried after {})", format_duration(delay)),
+		None => outcome,
+	}
+}
+
 #[allow(unused)]
 #[cfg(feature = "color-output")]
 const INDENTATION: u8 = 12;
 
 impl Response {
 	pub(crate) async fn from_reqwest_response(
+		client: &Client,
 		request_method: Method,
 		request_url: String,
-		client_cookies: Vec<Cookie>,
-		mut res: reqwest::Response,
+		sent: Sent,
 	) -> Result<Response> {
+		let Sent {
+			reqwest_res: mut res,
+			attempts,
+			ttfb,
+			request_body,
+			redirects,
+			final_method,
+			request_headers,
+		} = sent;
 		let status = res.status();
+		let final_url = res.url().to_string();
 
 		// Cookies from response
 		let cookies: Vec<Cookie> = res.cookies().map(Cookie::from).collect();
@@ -103,9 +215,10 @@ impl Response {
 		let header_map = HeaderMap::from_iter(headers);
 
 		// Capture the body
+		let body_start = Instant::now();
 		let ct = header_map.get("content-type").and_then(|v| v.to_str().ok());
 		let body = if let Some(ct) = ct {
-			if ct.starts_with("application/json") {
+			if is_json_content_type(ct) {
 				Body::Json(res.json::<Value>().await?)
 			} else if ct.starts_with("text/") {
 				Body::Text(res.text().await?)
@@ -115,136 +228,310 @@ impl Response {
 		} else {
 			Body::Other
 		};
+		let timing = Timing {
+			ttfb,
+			body: body_start.elapsed(),
+		};
+
+		// Note: Captured after the body so that the client cookies are the ones at the end of the call.
+		let client_cookies = client.client_cookies();
 
 		Ok(Response {
 			client_cookies,
 			request_method,
 			request_url,
+			request_body,
+			session_name: client.session_name().map(String::from),
 			status,
 			header_map,
 			cookies,
 			body,
+			attempts,
+			timing,
+			final_url,
+			final_method,
+			request_headers,
+			redirects,
+			print_options: client.print_options().clone(),
+			redaction: client.redaction_arc(),
 		})
 	}
 }
 
 impl Response {
 	// region:    --- Print Methods
+	/// Print the response with the client print options (default: everything).
 	pub async fn print(&self) -> Result<()> {
-		self.inner_print(true).await
+		self.inner_print(self.print_options.clone()).await
 	}
 
 	pub async fn print_no_body(&self) -> Result<()> {
-		self.inner_print(false).await
+		self.inner_print(self.print_options.clone().body(false)).await
+	}
+
+	/// Print the response with specific print options (e.g., `PrintOptions::default().json_pointer("/data")`).
+	pub async fn print_with(&self, options: PrintOptions) -> Result<()> {
+		self.inner_print(options).await
+	}
+
+	/// Same as `print`, for synchronous code (e.g., with the `blocking` client).
+	pub fn print_sync(&self) -> Result<()> {
+		self.print_with_sync(self.print_options.clone())
+	}
+
+	pub fn print_no_body_sync(&self) -> Result<()> {
+		self.print_with_sync(self.print_options.clone().body(false))
+	}
+
+	pub fn print_with_sync(&self, options: PrintOptions) -> Result<()> {
+		print!("{}", self.to_pretty_string_with(options));
+		Ok(())
+	}
+
+	/// Write the pretty printed response (same as `print`) to any writer (e.g., a log file, a buffer).
+	pub fn write_to(&self, w: &mut impl Write, options: PrintOptions) -> Result<()> {
+		self.write_pretty(w, &options)
+	}
+
+	/// Return the pretty printed response (same as `print`) as a String.
+	pub fn to_pretty_string(&self) -> String {
+		self.to_pretty_string_with(self.print_options.clone())
+	}
+
+	pub fn to_pretty_string_with(&self, options: PrintOptions) -> String {
+		let mut buf: Vec<u8> = Vec::new();
+		// Note: Writing to a Vec<u8> does not fail, and the body json is already a valid Value.
+		if let Err(err) = self.write_pretty(&mut buf, &options) {
+			return format!("<response print error: {err}>");
+		}
+		String::from_utf8_lossy(&buf).into_owned()
 	}
 
 	/// NOTE: For now, does not need to be async, but keeping the option of using async for later.
+	///       Printed with a single `print!` so that parallel tests do not interleave their output.
+	async fn inner_print(&self, options: PrintOptions) -> Result<()> {
+		print!("{}", self.to_pretty_string_with(options));
+		Ok(())
+	}
+
 	#[allow(unused)]
 	#[cfg(feature = "color-output")]
-	async fn inner_print(&self, body: bool) -> Result<()> {
+	fn write_pretty(&self, w: &mut impl Write, options: &PrintOptions) -> Result<()> {
 		let method_color = get_method_color(&self.request_method);
 		let method_background = get_method_background(&self.request_method);
-		let colored_url = split_and_color_url(&self.request_url);
+		let colored_url = split_and_color_url(&self.redacted_url());
 		let status_color = get_status_color(&self.status);
-		println!();
-		println!(
-			"{}: {}",
+		writeln!(w)?;
+		let session = self
+			.session_name
+			.as_ref()
+			.map(|name| format!("[{name}] ").cyan().to_string())
+			.unwrap_or_default();
+		writeln!(
+			w,
+			"{}: {} {}{}",
 			format_method(&self.request_method)
 				.bold()
 				.color(method_color)
 				.on_truecolor(50, 50, 50),
-			colored_url
-		);
-		println!(
+			colored_url,
+			session,
+			format!("({})", format_duration(self.elapsed())).dimmed()
+		)?;
+		writeln!(
+			w,
 			" {:<9} : {} {}",
 			"Status".blue(),
 			self.status.as_str().bold().color(status_color).on_black(),
 			self.status.canonical_reason().unwrap_or_default().color(status_color)
-		);
+		)?;
+
+		// Print the request body
+		if options.request {
+			if let Some(request_body) = &self.request_body {
+				writeln!(w, " {}:", "Request Body".blue())?;
+				match request_body {
+					PostContent::Json(val) => writeln!(w, "{}", options.render_json(&self.redaction.json(val))?.0)?,
+					PostContent::Text { body, .. } => {
+						writeln!(w, "    {}", options.render_text(&self.redaction.text(body)))?
+					}
+				}
+			}
+		}
+
+		// Print the request as a curl command
+		if options.curl {
+			writeln!(w, " {}:", "Curl".blue())?;
+			writeln!(w, "    {}", self.to_curl().replace('\n', "\n    ").dimmed())?;
+		}
 
 		// Print the response headers.
-		println!(" {:<9} :", "Headers".blue());
+		if options.headers {
+			writeln!(w, " {:<9} :", "Headers".blue())?;
 
-		for (n, v) in self.header_map.iter() {
-			println!("    {}: {}", n.to_string().yellow(), v.to_str().unwrap_or_default());
+			for (n, v) in self.header_map.iter().filter(|(n, _)| options.show_header(n.as_str())) {
+				let value = self.redaction.header_value(n.as_str(), v.to_str().unwrap_or_default());
+				writeln!(w, "    {}: {}", n.to_string().yellow(), value)?;
+			}
 		}
 
 		// Print the cookie_store
-		if !self.cookies.is_empty() {
-			println!(" {}:", "Response Cookies".blue());
+		if options.cookies && !self.cookies.is_empty() {
+			writeln!(w, " {}:", "Response Cookies".blue())?;
 			for c in self.cookies.iter() {
-				println!("    {}: {}", c.name.yellow(), c.value.bold());
+				let value = self.redaction.cookie_value(&c.name, &c.value);
+				writeln!(w, "    {}: {}", c.name.yellow(), value.bold())?;
 			}
 		}
 
 		// Print the cookie_store
-		if !self.client_cookies.is_empty() {
-			println!(" {}:", "Client Cookies".blue());
+		if options.cookies && !self.client_cookies.is_empty() {
+			writeln!(w, " {}:", "Client Cookies".blue())?;
 			for c in self.client_cookies.iter() {
-				println!("    {}: {}", c.name.yellow(), c.value.bold());
+				let value = self.redaction.cookie_value(&c.name, &c.value);
+				writeln!(w, "    {}: {}", c.name.yellow(), value.bold())?;
+			}
+		}
+
+		// Print the retry attempts (only when there was more than one)
+		if self.attempts.len() > 1 {
+			writeln!(w, " {}:", "Attempts".blue())?;
+			for (i, a) in self.attempts.iter().enumerate() {
+				writeln!(w, "    #{}: {}", i + 1, format_attempt(a).dimmed())?;
 			}
 		}
 
-		if body {
+		// Print the redirects followed
+		if !self.redirects.is_empty() {
+			writeln!(w, " {}:", "Redirects".blue())?;
+			for (i, r) in self.redirects.iter().enumerate() {
+				writeln!(w, "    #{}: {}", i + 1, self.format_redirect(r).dimmed())?;
+			}
+		}
+
+		if options.body {
 			// Print the body (json pretty print if json type)
-			println!("{}:", "Response Body".blue());
+			writeln!(w, "{}:", "Response Body".blue())?;
 			match &self.body {
-				Body::Json(val) => println!("{}", to_string_pretty(val)?.to_colored_json_auto()?),
-				Body::Text(val) => println!("    {}", val.color(status_color)),
+				Body::Json(val) => match options.render_json(&self.redaction.json(val))? {
+					// Note: `to_colored_json_auto` re-formats the content as pretty json.
+					(content, true) if options.compact => {
+						let value = serde_json::from_str::<Value>(&content)?;
+						let content = ColoredFormatter::new(CompactFormatter).to_colored_json_auto(&value)?;
+						writeln!(w, "{content}")?
+					}
+					(content, true) => writeln!(w, "{}", content.to_colored_json_auto()?)?,
+					(content, false) => writeln!(w, "{}", content)?,
+				},
+				Body::Text(val) => {
+					let text = options.render_text(&self.redaction.text(val));
+					writeln!(w, "    {}", text.color(status_color))?
+				}
 				_ => (),
 			}
 		}
 
-		println!("\n");
+		writeln!(w, "\n")?;
 		Ok(())
 	}
 
 	#[cfg(not(feature = "color-output"))]
-	async fn inner_print(&self, body: bool) -> Result<()> {
-		println!();
-		println!("=== Response for {} {}", self.request_method, &self.request_url);
-
-		println!(
+	fn write_pretty(&self, w: &mut impl Write, options: &PrintOptions) -> Result<()> {
+		writeln!(w)?;
+		let session = self
+			.session_name
+			.as_ref()
+			.map(|name| format!("[{name}] "))
+			.unwrap_or_default();
+		writeln!(
+			w,
+			"=== Response for {} {} {}({})",
+			self.request_method,
+			self.redacted_url(),
+			session,
+			format_duration(self.elapsed())
+		)?;
+
+		writeln!(
+			w,
 			"=> {:<15}: {} {}",
 			"Status",
 			self.status.as_str(),
 			self.status.canonical_reason().unwrap_or_default()
-		);
+		)?;
+
+		// Print the request body
+		if options.request {
+			if let Some(request_body) = &self.request_body {
+				writeln!(w, "=> {:<15}:", "Request Body")?;
+				match request_body {
+					PostContent::Json(val) => writeln!(w, "{}", options.render_json(&self.redaction.json(val))?.0)?,
+					PostContent::Text { body, .. } => {
+						writeln!(w, "{}", options.render_text(&self.redaction.text(body)))?
+					}
+				}
+			}
+		}
+
+		// Print the request as a curl command
+		if options.curl {
+			writeln!(w, "=> {:<15}:", "Curl")?;
+			writeln!(w, "   {}", self.to_curl().replace('\n', "\n   "))?;
+		}
 
 		// Print the response headers.
-		println!("=> {:<15}:", "Headers");
+		if options.headers {
+			writeln!(w, "=> {:<15}:", "Headers")?;
 
-		for (n, v) in self.header_map.iter() {
-			println!("   {}: {}", n, v.to_str().unwrap_or_default());
+			for (n, v) in self.header_map.iter().filter(|(n, _)| options.show_header(n.as_str())) {
+				let value = self.redaction.header_value(n.as_str(), v.to_str().unwrap_or_default());
+				writeln!(w, "   {}: {}", n, value)?;
+			}
 		}
 
 		// Print the cookie_store
-		if !self.cookies.is_empty() {
-			println!("=> {:<15}:", "Response Cookies");
+		if options.cookies && !self.cookies.is_empty() {
+			writeln!(w, "=> {:<15}:", "Response Cookies")?;
 			for c in self.cookies.iter() {
-				println!("   {}: {}", c.name, c.value);
+				writeln!(w, "   {}: {}", c.name, self.redaction.cookie_value(&c.name, &c.value))?;
 			}
 		}
 
 		// Print the cookie_store
-		if !self.client_cookies.is_empty() {
-			println!("=> {:<15}:", "Client Cookies");
+		if options.cookies && !self.client_cookies.is_empty() {
+			writeln!(w, "=> {:<15}:", "Client Cookies")?;
 			for c in self.client_cookies.iter() {
-				println!("   {}: {}", c.name, c.value);
+				writeln!(w, "   {}: {}", c.name, self.redaction.cookie_value(&c.name, &c.value))?;
 			}
 		}
 
-		if body {
+		// Print the retry attempts (only when there was more than one)
+		if self.attempts.len() > 1 {
+			writeln!(w, "=> {:<15}:", "Attempts")?;
+			for (i, a) in self.attempts.iter().enumerate() {
+				writeln!(w, "   #{}: {}", i + 1, format_attempt(a))?;
+			}
+		}
+
+		// Print the redirects followed
+		if !self.redirects.is_empty() {
+			writeln!(w, "=> {:<15}:", "Redirects")?;
+			for (i, r) in self.redirects.iter().enumerate() {
+				writeln!(w, "   #{}: {}", i + 1, self.format_redirect(r))?;
+			}
+		}
+
+		if options.body {
 			// Print the body (json pretty print if json type)
-			println!("=> {:<15}:", "Response Body");
+			writeln!(w, "=> {:<15}:", "Response Body")?;
 			match &self.body {
-				Body::Json(val) => println!("{}", to_string_pretty(val)?),
-				Body::Text(val) => println!("{}", val),
+				Body::Json(val) => writeln!(w, "{}", options.render_json(&self.redaction.json(val))?.0)?,
+				Body::Text(val) => writeln!(w, "{}", options.render_text(&self.redaction.text(val)))?,
 				_ => (),
 			}
 		}
 
-		println!("===\n");
+		writeln!(w, "===\n")?;
 		Ok(())
 	}
 
@@ -260,17 +547,172 @@ impl Response {
 	}
 
 	pub fn header(&self, name: &str) -> Option<String> {
-		self.header_map.get(name).and_then(|v| v.to_str().map(|v| v.to_string()).ok())
+		self.header_map
+			.get(name)
+			.and_then(|v| v.to_str().map(|v| v.to_string()).ok())
 	}
 	// endregion: --- Headers
 
+	// region:    --- Session
+	/// Return the name of the client session that made this call (see `Client::with_session_name`).
+	pub fn session_name(&self) -> Option<&str> {
+		self.session_name.as_deref()
+	}
+	// endregion: --- Session
+
+	// region:    --- Request
+	pub fn request_method(&self) -> &Method {
+		&self.request_method
+	}
+
+	pub fn request_url(&self) -> &str {
+		&self.request_url
+	}
+
+	/// The request url with the client redaction rules applied (for printing and error messages).
+	pub(crate) fn redacted_url(&self) -> String {
+		self.redaction.text(&self.request_url)
+	}
+	// endregion: --- Request
+
 	// region:    --- Status Code
 	/// Return the Response status code
 	pub fn status(&self) -> StatusCode {
 		self.status
 	}
+
+	/// Return the response, or an `Error::HttpStatus` with the response if the status is 4xx or 5xx.
+	pub fn error_for_status(self) -> Result<Self> {
+		if self.status.is_client_error() || self.status.is_server_error() {
+			Err(Error::HttpStatus {
+				status: self.status,
+				response: Box::new(self),
+			})
+		} else {
+			Ok(self)
+		}
+	}
 	// endregion: --- Status Code
 
+	// region:    --- Attempts
+	/// Return the attempts made for this call, the last one being this response.
+	/// Note: Empty when the client does not have a retry policy.
+	pub fn attempts(&self) -> &[Attempt] {
+		&self.attempts
+	}
+	// endregion: --- Attempts
+
+	// region:    --- Redirects
+	/// Return the method of the last request, which is GET if a redirect changed it (e.g., POST then 303).
+	pub fn final_method(&self) -> &Method {
+		&self.final_method
+	}
+
+	/// Return the url of this response, which is the url of the last redirect location if redirects were followed.
+	pub fn final_url(&self) -> &str {
+		&self.final_url
+	}
+
+	/// Return the redirects followed to get this response (empty if none, or if the client does not follow redirects).
+	pub fn redirects(&self) -> &[Redirect] {
+		&self.redirects
+	}
+
+	fn format_redirect(&self, redirect: &Redirect) -> String {
+		let status = &redirect.status;
+		let mut line = format!(
+			"{} {} {} -> {}",
+			status.as_str(),
+			status.canonical_reason().unwrap_or_default(),
+			self.redaction.text(&redirect.url),
+			self.redaction.text(&redirect.location)
+		);
+		if !redirect.cookies.is_empty() {
+			let names: Vec<&str> = redirect.cookies.iter().map(|c| c.name.as_str()).collect();
+			line.push_str(&format!(" (set-cookie: {})", names.join(", ")));
+		}
+		line
+	}
+	// endregion: --- Redirects
+
+	// region:    --- Curl
+	/// Return the (last) request as a curl command, redacted per the client redaction rules.
+	/// Note: The reqwest client default headers (if any) are not included.
+	pub fn to_curl(&self) -> String {
+		self.to_curl_with(&self.redaction)
+	}
+
+	/// Same as `to_curl` with other redaction rules (e.g., `Redaction::none()` for the actual values).
+	pub fn to_curl_with(&self, redaction: &Redaction) -> String {
+		// Note: The body is not re-sent when a redirect changed the method to GET.
+		let body = self
+			.request_body
+			.as_ref()
+			.filter(|_| self.final_method == self.request_method);
+		crate::curl::to_curl(
+			&self.final_method,
+			&self.final_url,
+			&self.request_headers,
+			body,
+			redaction,
+		)
+	}
+	// endregion: --- Curl
+
+	// region:    --- Tracing
+	/// The request body (if sent to the final url), redacted, for the trace logs.
+	#[cfg(feature = "tracing")]
+	pub(crate) fn redacted_request_body(&self) -> Option<String> {
+		let body = self
+			.request_body
+			.as_ref()
+			.filter(|_| self.final_method == self.request_method)?;
+		let body = match body {
+			PostContent::Json(val) => self.redaction.json(val).to_string(),
+			PostContent::Text { body, .. } => self.redaction.text(body),
+		};
+		Some(body)
+	}
+
+	/// The response body (if json or text), redacted, for the trace logs.
+	#[cfg(feature = "tracing")]
+	pub(crate) fn redacted_body(&self) -> Option<String> {
+		match &self.body {
+			Body::Json(val) => Some(self.redaction.json(val).to_string()),
+			Body::Text(val) => Some(self.redaction.text(val)),
+			Body::Other => None,
+		}
+	}
+	// endregion: --- Tracing
+
+	// region:    --- Timing
+	/// Return the total elapsed time of the call (time to first byte + body download).
+	/// Note: When retried, only the last attempt is measured.
+	pub fn elapsed(&self) -> Duration {
+		self.timing.total()
+	}
+
+	/// Return the detailed timing of the call.
+	pub fn timing(&self) -> &Timing {
+		&self.timing
+	}
+
+	/// Return an `Error::ElapsedAboveLimit` if the call took `limit` or more.
+	pub fn expect_elapsed_below(&self, limit: Duration) -> Result<&Self> {
+		let elapsed = self.elapsed();
+		if elapsed < limit {
+			Ok(self)
+		} else {
+			Err(Error::ElapsedAboveLimit {
+				request_method: self.request_method.clone(),
+				request_url: self.redacted_url(),
+				elapsed,
+				limit,
+			})
+		}
+	}
+	// endregion: --- Timing
+
 	// region:    --- Response Cookie
 	/// Return the cookie that has been set for this http response.
 	pub fn res_cookie(&self, name: &str) -> Option<&Cookie> {
@@ -295,7 +737,10 @@ impl Response {
 	/// Note: The response.client_cookies are the captured client cookies
 	///       at the time of the response.
 	pub fn client_cookie_value(&self, name: &str) -> Option<String> {
-		self.client_cookies.iter().find(|c| c.name == name).map(|c| c.value.clone())
+		self.client_cookies
+			.iter()
+			.find(|c| c.name == name)
+			.map(|c| c.value.clone())
 	}
 	// endregion: --- Client Cookies
 
@@ -326,15 +771,109 @@ impl Response {
 			json_pointer: pointer.to_string(),
 		})?;
 
-		Ok(serde_json::from_value::<T>(value.clone())?)
+		self.deserialize_json(pointer, value)
 	}
 
 	pub fn json_body_as<T>(&self) -> Result<T>
 	where
 		T: DeserializeOwned,
 	{
-		self.json_body()
-			.and_then(|val| serde_json::from_value::<T>(val).map_err(Error::SerdeJson))
+		let Body::Json(body) = &self.body else {
+			return Err(Error::Static("No json body"));
+		};
+
+		self.deserialize_json("", body)
+	}
+
+	/// Deserialize the json body of an error response (non 2xx status), e.g., as a `ProblemDetails`.
+	pub fn json_error_as<E>(&self) -> Result<E>
+	where
+		E: DeserializeOwned,
+	{
+		if self.status.is_success() {
+			return Err(Error::NotAnErrorResponse { status: self.status });
+		}
+		self.json_body_as::<E>()
+	}
+
+	/// Deserialize the json body as `T` for a 2xx status, or as `E` otherwise.
+	pub fn json_result_as<T, E>(&self) -> Result<std::result::Result<T, E>>
+	where
+		T: DeserializeOwned,
+		E: DeserializeOwned,
+	{
+		if self.status.is_success() {
+			self.json_body_as::<T>().map(Ok)
+		} else {
+			self.json_body_as::<E>().map(Err)
+		}
+	}
+
+	/// Validate the json body against the JSON Schema (draft per the schema `$schema`, 2020-12 by default).
+	/// Returns an `Error::JsonSchemaViolations` with all the violations if the body does not validate.
+	#[cfg(feature = "json-schema")]
+	pub fn expect_json_schema(&self, schema: &Value) -> Result<&Self> {
+		let Body::Json(body) = &self.body else {
+			return Err(Error::Static("No json body"));
+		};
+
+		let violations = crate::json_schema::validate(schema, body, &self.redaction.json(body))?;
+		if violations.is_empty() {
+			Ok(self)
+		} else {
+			Err(Error::JsonSchemaViolations {
+				request: format!("{} {}", self.request_method, self.redacted_url()),
+				violations,
+			})
+		}
+	}
+
+	/// Same as `expect_json_schema` with the schema loaded from a json file.
+	#[cfg(feature = "json-schema")]
+	pub fn validate_json_schema(&self, path: impl AsRef<std::path::Path>) -> Result<&Self> {
+		let schema = serde_json::from_str::<Value>(&std::fs::read_to_string(path)?)?;
+		self.expect_json_schema(&schema)
+	}
+
+	/// Deserialize the value, with the request, json pointer, failing field path and json snippet on error.
+	fn deserialize_json<T>(&self, pointer: &str, value: &Value) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		serde_path_to_error::deserialize::<_, T>(value).map_err(|err| {
+			let path = err.path().to_string();
+			// Note: Redact the whole body, so that the body json pointer redactions apply.
+			let redacted = match &self.body {
+				Body::Json(body) => self.redaction.json(body).pointer(pointer).cloned(),
+				_ => None,
+			};
+			Error::JsonDeserialize {
+				request: format!("{} {}", self.request_method, self.redacted_url()),
+				json_pointer: pointer.to_string(),
+				type_name: std::any::type_name::<T>(),
+				path,
+				snippet: json_snippet(&redacted.unwrap_or_else(|| self.redaction.json(value))),
+				source: err.into_inner(),
+			}
+		})
 	}
 	// endregion: --- Body
 }
+
+/// Note: Only the request and status (redacted), as the printed form is available with `Display`.
+impl fmt::Debug for Response {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Response")
+			.field("request_method", &self.request_method)
+			.field("request_url", &self.redacted_url())
+			.field("session_name", &self.session_name)
+			.field("status", &self.status)
+			.finish_non_exhaustive()
+	}
+}
+
+impl fmt::Display for Response {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.to_pretty_string())
+	}
+}
@@ -0,0 +1,185 @@
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// Retry policy applied by the `Client` to every `do_...` call.
+///
+/// Default: 3 attempts, exponential backoff from 100ms (capped at 2s) with jitter,
+///          retry on 502, 503, 504 and connection errors, honoring `Retry-After` (capped at 10s).
+#[derive(Clone)]
+pub struct RetryPolicy {
+	max_attempts: u32,
+	initial_backoff: Duration,
+	max_backoff: Duration,
+	jitter: bool,
+	retry_statuses: Vec<StatusCode>,
+	retry_connection_errors: bool,
+	honor_retry_after: bool,
+	max_retry_after: Duration,
+	retry_if: Option<Arc<RetryPredicate>>,
+}
+
+type RetryPredicate = dyn Fn(&AttemptOutcome) -> bool + Send + Sync;
+
+/// Outcome of one send attempt, as given to the `RetryPolicy::retry_if` predicate.
+pub enum AttemptOutcome<'a> {
+	Status(StatusCode),
+	Error(&'a reqwest::Error),
+}
+
+/// Record of one attempt of a http call, available from `Response::attempts()`.
+#[derive(Debug, Clone)]
+pub struct Attempt {
+	/// The status received (None if the attempt failed with a connection error)
+	pub status: Option<StatusCode>,
+	/// The error message if the attempt failed without a response
+	pub error: Option<String>,
+	/// The delay waited before the next attempt (None for the last attempt)
+	pub delay: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		RetryPolicy {
+			max_attempts: 3,
+			initial_backoff: Duration::from_millis(100),
+			max_backoff: Duration::from_secs(2),
+			jitter: true,
+			retry_statuses: vec![
+				StatusCode::BAD_GATEWAY,
+				StatusCode::SERVICE_UNAVAILABLE,
+				StatusCode::GATEWAY_TIMEOUT,
+			],
+			retry_connection_errors: true,
+			honor_retry_after: true,
+			max_retry_after: Duration::from_secs(10),
+			retry_if: None,
+		}
+	}
+}
+
+// region:    --- Builder Methods
+impl RetryPolicy {
+	/// Create a default policy with a given maximum number of attempts (including the first one).
+	pub fn new(max_attempts: u32) -> Self {
+		RetryPolicy {
+			max_attempts: max_attempts.max(1),
+			..Default::default()
+		}
+	}
+
+	/// Set the first backoff delay, doubled on each attempt, and its upper bound.
+	pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+		self.initial_backoff = initial;
+		self.max_backoff = max;
+		self
+	}
+
+	/// Randomize each backoff delay between half and the full computed delay (default true).
+	pub fn jitter(mut self, jitter: bool) -> Self {
+		self.jitter = jitter;
+		self
+	}
+
+	/// Replace the set of status codes that trigger a retry.
+	pub fn retry_on_statuses(mut self, statuses: impl IntoIterator<Item = impl Into<u16>>) -> Self {
+		self.retry_statuses = statuses
+			.into_iter()
+			.filter_map(|s| StatusCode::from_u16(s.into()).ok())
+			.collect();
+		self
+	}
+
+	/// Retry when the request could not be sent or the connection was reset (default true).
+	pub fn retry_on_connection_errors(mut self, retry: bool) -> Self {
+		self.retry_connection_errors = retry;
+		self
+	}
+
+	/// Use the `Retry-After` response header (seconds or http-date) as the delay when present (default true).
+	pub fn honor_retry_after(mut self, honor: bool) -> Self {
+		self.honor_retry_after = honor;
+		self
+	}
+
+	/// Upper bound of the `Retry-After` delay (default 10s), so that a server asking for a long wait
+	/// (e.g., `Retry-After: 3600`) does not stall the test.
+	/// Note: Separate from the backoff max, as the `Retry-After` delays are often longer than the backoff ones.
+	pub fn max_retry_after(mut self, max: Duration) -> Self {
+		self.max_retry_after = max;
+		self
+	}
+
+	/// Custom predicate, evaluated in addition to the status set and connection errors rules.
+	pub fn retry_if(mut self, predicate: impl Fn(&AttemptOutcome) -> bool + Send + Sync + 'static) -> Self {
+		self.retry_if = Some(Arc::new(predicate));
+		self
+	}
+}
+// endregion: --- Builder Methods
+
+// region:    --- Crate Privates
+impl RetryPolicy {
+	pub(crate) fn max_attempts(&self) -> u32 {
+		self.max_attempts
+	}
+
+	pub(crate) fn should_retry(&self, outcome: &AttemptOutcome) -> bool {
+		let by_rules = match outcome {
+			AttemptOutcome::Status(status) => self.retry_statuses.contains(status),
+			AttemptOutcome::Error(err) => {
+				self.retry_connection_errors && (err.is_connect() || err.is_timeout() || err.is_request())
+			}
+		};
+		by_rules || self.retry_if.as_ref().map(|f| f(outcome)).unwrap_or(false)
+	}
+
+	/// Delay before the attempt following `attempt` (1 based).
+	pub(crate) fn delay_for(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+		if self.honor_retry_after {
+			if let Some(delay) = headers.and_then(retry_after) {
+				return delay.min(self.max_retry_after);
+			}
+		}
+
+		let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+		let delay = self.initial_backoff.saturating_mul(factor).min(self.max_backoff);
+
+		if self.jitter {
+			let half = delay / 2;
+			half + half.mul_f64(fastrand::f64())
+		} else {
+			delay
+		}
+	}
+}
+
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+	let val = headers.get("retry-after")?.to_str().ok()?.trim();
+
+	if let Ok(secs) = val.parse::<u64>() {
+		return Some(Duration::from_secs(secs));
+	}
+
+	let date = httpdate::parse_http_date(val).ok()?;
+	Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+// endregion: --- Crate Privates
+
+impl From<&AttemptOutcome<'_>> for Attempt {
+	fn from(val: &AttemptOutcome) -> Self {
+		match val {
+			AttemptOutcome::Status(status) => Attempt {
+				status: Some(*status),
+				error: None,
+				delay: None,
+			},
+			AttemptOutcome::Error(err) => Attempt {
+				status: None,
+				error: Some(err.to_string()),
+				delay: None,
+			},
+		}
+	}
+}
@@ -0,0 +1,84 @@
+use crate::{Error, Response, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt;
+
+/// JSON-RPC 2.0 error object, returned in `Error::Rpc` (or in the results of `Client::rpc_batch`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcError {
+	pub code: i64,
+	pub message: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub data: Option<Value>,
+}
+
+/// Result of one call of a `Client::rpc_batch`.
+pub type RpcResult = std::result::Result<Value, RpcError>;
+
+impl fmt::Display for RpcError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} (code: {})", self.message, self.code)?;
+		if let Some(data) = &self.data {
+			write!(f, " data: {data}")?;
+		}
+		Ok(())
+	}
+}
+
+/// Note: The `params` are omitted when null (JSON-RPC 2.0 params must be an array or object, if present).
+pub(crate) fn request(method: &str, params: Value, id: u64) -> Value {
+	let mut req = json!({
+		"jsonrpc": "2.0",
+		"method": method,
+		"id": id,
+	});
+	if !params.is_null() {
+		req["params"] = params;
+	}
+	req
+}
+
+/// Return the json body of the JSON-RPC response, or an `Error::HttpStatus` (with the response)
+/// for an http error without json body (e.g., a 502 html page of a proxy).
+pub(crate) fn json_body(res: Response) -> Result<Value> {
+	if let Ok(body) = res.json_body() {
+		return Ok(body);
+	}
+	let status = res.status();
+	res.error_for_status()?;
+	Err(invalid(format!("response (status {status}) does not have a json body")))
+}
+
+/// Return the result or error of a JSON-RPC response object, after checking its version and id.
+pub(crate) fn into_result(res: Value, id: u64) -> Result<RpcResult> {
+	let Value::Object(mut obj) = res else {
+		return Err(invalid(format!("response is not a json object: {res}")));
+	};
+
+	if obj.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+		return Err(invalid(format!(
+			"missing or invalid 'jsonrpc' version: {:?}",
+			obj.get("jsonrpc")
+		)));
+	}
+
+	// Note: The id is null when the server could not read the request id (e.g., parse error),
+	//       in which case the error is returned rather than an id mismatch.
+	let res_id = obj.get("id").cloned().unwrap_or(Value::Null);
+	if res_id != json!(id) && !(res_id.is_null() && obj.contains_key("error")) {
+		return Err(Error::RpcIdMismatch {
+			expected: id,
+			actual: res_id,
+		});
+	}
+
+	match (obj.remove("result"), obj.remove("error")) {
+		(Some(result), None) => Ok(Ok(result)),
+		(None, Some(error)) => Ok(Err(serde_json::from_value::<RpcError>(error)?)),
+		_ => Err(invalid("response must have either 'result' or 'error'".to_string())),
+	}
+}
+
+fn invalid(reason: String) -> Error {
+	Error::RpcInvalidResponse { reason }
+}
@@ -0,0 +1,181 @@
+use crate::stream::StreamResponse;
+use crate::{Error, Result};
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// One Server-Sent Event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SseEvent {
+	/// The `event` field (`message` when not set by the server)
+	pub event: String,
+	/// The `data` field, with the lines of multi-line data joined by `\n`
+	pub data: String,
+	/// The last event id (the `id` field of this event or of a previous one)
+	pub id: Option<String>,
+	/// The `retry` field (reconnection time)
+	pub retry: Option<Duration>,
+}
+
+impl SseEvent {
+	/// Deserialize the event data as json.
+	pub fn data_as<T>(&self) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		Ok(serde_json::from_str::<T>(&self.data)?)
+	}
+}
+
+/// Server-Sent Events reader over a `StreamResponse` (see `StreamResponse::into_sse`).
+pub struct SseStream {
+	res: StreamResponse,
+	parser: SseParser,
+	events: VecDeque<SseEvent>,
+	ended: bool,
+}
+
+impl SseStream {
+	pub(crate) fn new(res: StreamResponse) -> Self {
+		SseStream {
+			res,
+			parser: SseParser::default(),
+			events: VecDeque::new(),
+			ended: false,
+		}
+	}
+
+	/// The underlying stream response (e.g., for the status or headers).
+	pub fn response(&self) -> &StreamResponse {
+		&self.res
+	}
+
+	/// Return the next event, or None when the stream is closed by the server.
+	pub async fn next_event(&mut self) -> Result<Option<SseEvent>> {
+		loop {
+			if let Some(event) = self.events.pop_front() {
+				return Ok(Some(event));
+			}
+			if self.ended {
+				return Ok(None);
+			}
+			match self.res.next_chunk().await? {
+				Some(chunk) => self.events.extend(self.parser.push(&chunk)),
+				None => {
+					self.ended = true;
+					self.events.extend(self.parser.finish());
+				}
+			}
+		}
+	}
+
+	/// Return the next event if received within `duration`,
+	/// otherwise an `Error::SseTimeout` (or `Error::SseStreamEnded` if the stream was closed).
+	pub async fn next_event_within(&mut self, duration: Duration) -> Result<SseEvent> {
+		match tokio::time::timeout(duration, self.next_event()).await {
+			Ok(Ok(Some(event))) => Ok(event),
+			Ok(Ok(None)) => Err(Error::SseStreamEnded),
+			Ok(Err(err)) => Err(err),
+			Err(_) => Err(Error::SseTimeout { within: duration }),
+		}
+	}
+
+	/// Return the data of the next event (received within `duration`) deserialized as json.
+	pub async fn next_json_within<T>(&mut self, duration: Duration) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		self.next_event_within(duration).await?.data_as::<T>()
+	}
+}
+
+// region:    --- Parser
+/// Incremental parser of the `text/event-stream` format
+/// (see https://html.spec.whatwg.org/multipage/server-sent-events.html#event-stream-interpretation).
+#[derive(Default)]
+pub(crate) struct SseParser {
+	buf: Vec<u8>,
+	event: Option<String>,
+	data: Option<String>,
+	retry: Option<Duration>,
+	last_event_id: Option<String>,
+}
+
+impl SseParser {
+	/// Push a chunk of bytes, and return the events completed by it.
+	pub(crate) fn push(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+		self.buf.extend_from_slice(chunk);
+
+		let mut events = Vec::new();
+		while let Some((line_end, next_start)) = self.next_line_bounds() {
+			let line: Vec<u8> = self.buf.drain(..next_start).take(line_end).collect();
+			let line = String::from_utf8_lossy(&line);
+			if let Some(event) = self.process_line(&line) {
+				events.push(event);
+			}
+		}
+		events
+	}
+
+	/// End of stream. Per spec, an incomplete event (no blank line) is discarded.
+	pub(crate) fn finish(&mut self) -> Vec<SseEvent> {
+		self.buf.clear();
+		Vec::new()
+	}
+
+	/// Return the end of the first complete line and the start of the next one.
+	/// Note: A `\r` at the end of the buffer waits for the next chunk, as it might be a `\r\n`.
+	fn next_line_bounds(&self) -> Option<(usize, usize)> {
+		let pos = self.buf.iter().position(|b| *b == b'\n' || *b == b'\r')?;
+		match (self.buf[pos], self.buf.get(pos + 1)) {
+			(b'\r', Some(b'\n')) => Some((pos, pos + 2)),
+			(b'\r', None) => None,
+			_ => Some((pos, pos + 1)),
+		}
+	}
+
+	fn process_line(&mut self, line: &str) -> Option<SseEvent> {
+		// blank line, dispatch the event
+		if line.is_empty() {
+			let event = self.event.take();
+			let data = self.data.take()?;
+			return Some(SseEvent {
+				event: event.unwrap_or_else(|| "message".to_string()),
+				data,
+				id: self.last_event_id.clone(),
+				retry: self.retry.take(),
+			});
+		}
+
+		// comment
+		if line.starts_with(':') {
+			return None;
+		}
+
+		let (field, value) = match line.split_once(':') {
+			Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+			None => (line, ""),
+		};
+
+		match field {
+			"event" => self.event = Some(value.to_string()),
+			"data" => match &mut self.data {
+				Some(data) => {
+					data.push('\n');
+					data.push_str(value);
+				}
+				None => self.data = Some(value.to_string()),
+			},
+			"id" if !value.contains('\0') => self.last_event_id = Some(value.to_string()),
+			"retry" => {
+				if let Ok(millis) = value.parse::<u64>() {
+					self.retry = Some(Duration::from_millis(millis));
+				}
+			}
+			_ => (),
+		}
+
+		None
+	}
+}
+// endregion: --- Parser
@@ -0,0 +1,72 @@
+use crate::cookie::Cookie;
+use crate::sse::SseStream;
+use crate::Result;
+use bytes::Bytes;
+use futures_core::Stream;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+
+/// Response of `Client::do_get_stream`, returned as soon as the status and headers are received.
+/// The body is read incrementally with `next_chunk`, `bytes_stream`, or as Server-Sent Events with `into_sse`.
+pub struct StreamResponse {
+	request_method: Method,
+	request_url: String,
+	status: StatusCode,
+	cookies: Vec<Cookie>,
+	reqwest_res: reqwest::Response,
+}
+
+impl StreamResponse {
+	pub(crate) fn new(request_method: Method, request_url: String, reqwest_res: reqwest::Response) -> Self {
+		StreamResponse {
+			request_method,
+			request_url,
+			status: reqwest_res.status(),
+			cookies: reqwest_res.cookies().map(Cookie::from).collect(),
+			reqwest_res,
+		}
+	}
+
+	pub fn request_method(&self) -> &Method {
+		&self.request_method
+	}
+
+	pub fn request_url(&self) -> &str {
+		&self.request_url
+	}
+
+	/// Return the Response status code
+	pub fn status(&self) -> StatusCode {
+		self.status
+	}
+
+	pub fn headers(&self) -> &HeaderMap {
+		self.reqwest_res.headers()
+	}
+
+	pub fn header(&self, name: &str) -> Option<String> {
+		self.headers()
+			.get(name)
+			.and_then(|v| v.to_str().map(|v| v.to_string()).ok())
+	}
+
+	/// Return the cookie that has been set for this http response.
+	pub fn res_cookie(&self, name: &str) -> Option<&Cookie> {
+		self.cookies.iter().find(|c| c.name == name)
+	}
+
+	/// Return the next chunk of the body, or None when the body is complete.
+	pub async fn next_chunk(&mut self) -> Result<Option<Bytes>> {
+		Ok(self.reqwest_res.chunk().await?)
+	}
+
+	/// Return the body as an async stream of chunks.
+	pub fn bytes_stream(self) -> impl Stream<Item = reqwest::Result<Bytes>> {
+		self.reqwest_res.bytes_stream()
+	}
+
+	/// Read the body as Server-Sent Events (`text/event-stream`).
+	pub fn into_sse(self) -> SseStream {
+		SseStream::new(self)
+	}
+}
@@ -0,0 +1,100 @@
+//! Tracing of the calls (with the `tracing` feature).
+
+use crate::middleware::{Middleware, RequestParts};
+use crate::{Error, Response, Result};
+use async_trait::async_trait;
+use reqwest::header::{HeaderName, HeaderValue};
+use reqwest::Method;
+use tracing::field::Empty;
+use tracing::{debug, trace, Span};
+
+/// W3C trace context header
+const TRACEPARENT: HeaderName = HeaderName::from_static("traceparent");
+
+/// Span of one call, with the `status`, `elapsed_ms` (and `trace_id`) recorded when known.
+pub(crate) fn exchange_span(method: &Method, url: &str) -> Span {
+	tracing::info_span!(
+		"httpc_test",
+		method = %method,
+		url = %url,
+		status = Empty,
+		elapsed_ms = Empty,
+		trace_id = Empty
+	)
+}
+
+/// Record the response in the current (call) span, with the redacted bodies at trace level.
+pub(crate) fn record_response(res: &Response) {
+	let span = Span::current();
+	span.record("status", res.status().as_u16());
+	span.record("elapsed_ms", res.elapsed().as_millis() as u64);
+	debug!(
+		status = res.status().as_u16(),
+		elapsed_ms = res.elapsed().as_millis() as u64,
+		"response"
+	);
+
+	if tracing::enabled!(tracing::Level::TRACE) {
+		if let Some(body) = res.redacted_request_body() {
+			trace!(body = %body, "request");
+		}
+		if let Some(body) = res.redacted_body() {
+			trace!(body = %body, "response");
+		}
+	}
+}
+
+pub(crate) fn record_error(err: &Error) {
+	debug!(error = %err, "request failed");
+}
+
+// region:    --- TraceparentMiddleware
+/// Middleware injecting a W3C `traceparent` header in each request (unless already set),
+/// so that the server traces can be correlated with the test.
+///
+/// All the calls share the trace id of the middleware (e.g., one per test), each with a new parent id.
+/// The trace id is recorded in the `trace_id` field of the call span.
+#[derive(Debug, Clone)]
+pub struct TraceparentMiddleware {
+	trace_id: u128,
+}
+
+impl Default for TraceparentMiddleware {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl TraceparentMiddleware {
+	/// New middleware with a random trace id.
+	pub fn new() -> Self {
+		// Note: An all zero trace id is invalid.
+		TraceparentMiddleware {
+			trace_id: fastrand::u128(1..),
+		}
+	}
+
+	/// The trace id, as 32 lowercase hex digits (as in the header).
+	pub fn trace_id(&self) -> String {
+		format!("{:032x}", self.trace_id)
+	}
+}
+
+#[async_trait]
+impl Middleware for TraceparentMiddleware {
+	async fn before_request(&self, parts: &mut RequestParts) -> Result<()> {
+		if parts.headers.contains_key(TRACEPARENT) {
+			return Ok(());
+		}
+		let trace_id = self.trace_id();
+		let parent_id = fastrand::u64(1..);
+		let traceparent = format!("00-{trace_id}-{parent_id:016x}-01");
+		// Note: Cannot fail, as the value is made of hex digits and dashes.
+		parts
+			.headers
+			.insert(TRACEPARENT, HeaderValue::from_str(&traceparent).unwrap());
+		Span::current().record("trace_id", tracing::field::display(&trace_id));
+		Ok(())
+	}
+}
+// endregion: --- TraceparentMiddleware
@@ -0,0 +1,296 @@
+use crate::redaction::Redaction;
+use crate::response::format_duration;
+use crate::{Client, Error, Result};
+use futures_util::{SinkExt, StreamExt};
+use reqwest::cookie::CookieStore;
+use reqwest::header::{COOKIE, SET_COOKIE};
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use std::io::Write;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+#[allow(unused)]
+#[cfg(feature = "color-output")]
+use colored::*;
+
+/// WebSocket test client returned by `Client::ws_connect`, sharing the client cookie session.
+///
+/// All the sent and received messages are recorded in a transcript (see `transcript()` and `print()`).
+///
+/// Default: 5s timeout for the `recv_...` and `expect_...` methods.
+pub struct WsClient {
+	url: String,
+	session_name: Option<String>,
+	socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+	timeout: Duration,
+	connected_at: Instant,
+	transcript: Vec<WsTranscriptEntry>,
+	redaction: Arc<Redaction>,
+}
+
+/// One sent or received WebSocket message.
+#[derive(Debug, Clone)]
+pub struct WsTranscriptEntry {
+	pub direction: WsDirection,
+	/// The text message, or a `<binary N bytes>` / `<close>` description
+	pub message: String,
+	/// Time since the connection
+	pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WsDirection {
+	Sent,
+	Received,
+}
+
+pub(crate) async fn connect(client: &Client, url: String) -> Result<WsClient> {
+	let http_url = Url::parse(&url).map_err(|err| Error::Generic(format!("Invalid url '{url}': {err}")))?;
+	let mut ws_url = http_url.clone();
+	let scheme = if http_url.scheme() == "https" { "wss" } else { "ws" };
+	// Note: Cannot fail for http(s) urls.
+	let _ = ws_url.set_scheme(scheme);
+
+	let cookie_store = client.cookie_store();
+	let mut request = ws_url.as_str().into_client_request()?;
+	if let Some(cookie) = cookie_store.cookies(&http_url) {
+		request.headers_mut().insert(COOKIE, cookie);
+	}
+
+	let (socket, res) = tokio_tungstenite::connect_async(request).await?;
+	cookie_store.set_cookies(&mut res.headers().get_all(SET_COOKIE).iter(), &http_url);
+
+	Ok(WsClient {
+		url: ws_url.to_string(),
+		session_name: client.session_name().map(String::from),
+		socket,
+		timeout: Duration::from_secs(5),
+		connected_at: Instant::now(),
+		transcript: Vec::new(),
+		redaction: client.redaction_arc(),
+	})
+}
+
+// region:    --- Send / Receive
+impl WsClient {
+	/// Set the timeout for the `recv_...` and `expect_...` methods.
+	pub fn with_timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = timeout;
+		self
+	}
+
+	pub async fn send_text(&mut self, text: impl Into<String>) -> Result<()> {
+		let text: String = text.into();
+		self.record(WsDirection::Sent, text.clone());
+		self.socket.send(Message::text(text)).await?;
+		Ok(())
+	}
+
+	pub async fn send_json(&mut self, value: &impl Serialize) -> Result<()> {
+		self.send_text(serde_json::to_string(value)?).await
+	}
+
+	/// Receive the next text message (ping/pong are skipped).
+	/// Error if none is received within the timeout, or if the socket is closed.
+	pub async fn recv_text(&mut self) -> Result<String> {
+		let timeout = self.timeout;
+		match tokio::time::timeout(timeout, self.next_text()).await {
+			Ok(res) => res,
+			Err(_) => Err(Error::WsTimeout { within: timeout }),
+		}
+	}
+
+	/// Receive the next text message deserialized as json.
+	pub async fn recv_json<T>(&mut self) -> Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		let text = self.recv_text().await?;
+		Ok(serde_json::from_str::<T>(&text)?)
+	}
+
+	/// Receive messages until one matches the predicate, and return it.
+	/// The non-matching messages are skipped (but still in the transcript).
+	pub async fn expect_message(&mut self, predicate: impl Fn(&str) -> bool) -> Result<String> {
+		let timeout = self.timeout;
+		let expect = async {
+			loop {
+				let text = self.next_text().await?;
+				if predicate(&text) {
+					return Ok(text);
+				}
+			}
+		};
+		match tokio::time::timeout(timeout, expect).await {
+			Ok(res) => res,
+			Err(_) => Err(Error::WsTimeout { within: timeout }),
+		}
+	}
+
+	/// Same as `expect_message` for json messages (the non-json messages are skipped).
+	pub async fn expect_json(&mut self, predicate: impl Fn(&Value) -> bool) -> Result<Value> {
+		let text = self
+			.expect_message(|text| {
+				serde_json::from_str::<Value>(text)
+					.map(|v| predicate(&v))
+					.unwrap_or(false)
+			})
+			.await?;
+		Ok(serde_json::from_str::<Value>(&text)?)
+	}
+
+	pub async fn close(&mut self) -> Result<()> {
+		self.record(WsDirection::Sent, "<close>".to_string());
+		self.socket.close(None).await?;
+		Ok(())
+	}
+
+	async fn next_text(&mut self) -> Result<String> {
+		loop {
+			let Some(msg) = self.socket.next().await else {
+				return Err(Error::WsClosed);
+			};
+			match msg? {
+				Message::Text(text) => {
+					let text = text.to_string();
+					self.record(WsDirection::Received, text.clone());
+					return Ok(text);
+				}
+				Message::Binary(data) => self.record(WsDirection::Received, format!("<binary {} bytes>", data.len())),
+				Message::Close(_) => {
+					self.record(WsDirection::Received, "<close>".to_string());
+					return Err(Error::WsClosed);
+				}
+				Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => (),
+			}
+		}
+	}
+
+	fn record(&mut self, direction: WsDirection, message: String) {
+		self.transcript.push(WsTranscriptEntry {
+			direction,
+			message,
+			elapsed: self.connected_at.elapsed(),
+		});
+	}
+}
+// endregion: --- Send / Receive
+
+// region:    --- Transcript
+impl WsClient {
+	pub fn url(&self) -> &str {
+		&self.url
+	}
+
+	pub fn transcript(&self) -> &[WsTranscriptEntry] {
+		&self.transcript
+	}
+
+	/// Print the transcript (same style as `Response::print`).
+	pub async fn print(&self) -> Result<()> {
+		print!("{}", self.to_pretty_string());
+		Ok(())
+	}
+
+	pub fn write_to(&self, w: &mut impl Write) -> Result<()> {
+		self.write_pretty(w)
+	}
+
+	pub fn to_pretty_string(&self) -> String {
+		let mut buf: Vec<u8> = Vec::new();
+		if let Err(err) = self.write_pretty(&mut buf) {
+			return format!("<transcript print error: {err}>");
+		}
+		String::from_utf8_lossy(&buf).into_owned()
+	}
+
+	/// The message redacted per the client redaction rules (json messages are redacted as json bodies).
+	fn redacted_message(&self, message: &str) -> String {
+		match serde_json::from_str::<Value>(message) {
+			Ok(value @ (Value::Object(_) | Value::Array(_))) => self.redaction.json(&value).to_string(),
+			_ => self.redaction.text(message),
+		}
+	}
+
+	#[cfg(feature = "color-output")]
+	fn write_pretty(&self, w: &mut impl Write) -> Result<()> {
+		let session = self
+			.session_name
+			.as_ref()
+			.map(|name| format!("[{name}] ").cyan().to_string())
+			.unwrap_or_default();
+		writeln!(w)?;
+		writeln!(
+			w,
+			"{}: {} {}{}",
+			" WS        ".bold().on_truecolor(50, 50, 50),
+			self.redaction.text(&self.url).purple(),
+			session,
+			format!("({} messages)", self.transcript.len()).dimmed()
+		)?;
+		writeln!(w, " {:<9} :", "Transcript".blue())?;
+		for entry in self.transcript.iter() {
+			let arrow = match entry.direction {
+				WsDirection::Sent => ">>".green(),
+				WsDirection::Received => "<<".yellow(),
+			};
+			writeln!(
+				w,
+				"    {} {} {}",
+				format!("+{:<7}", format_duration(entry.elapsed)).dimmed(),
+				arrow,
+				self.redacted_message(&entry.message)
+			)?;
+		}
+		writeln!(w, "\n")?;
+		Ok(())
+	}
+
+	#[cfg(not(feature = "color-output"))]
+	fn write_pretty(&self, w: &mut impl Write) -> Result<()> {
+		let session = self
+			.session_name
+			.as_ref()
+			.map(|name| format!("[{name}] "))
+			.unwrap_or_default();
+		writeln!(w)?;
+		writeln!(
+			w,
+			"=== WebSocket {} {}({} messages)",
+			self.redaction.text(&self.url),
+			session,
+			self.transcript.len()
+		)?;
+		writeln!(w, "=> {:<15}:", "Transcript")?;
+		for entry in self.transcript.iter() {
+			let arrow = match entry.direction {
+				WsDirection::Sent => ">>",
+				WsDirection::Received => "<<",
+			};
+			writeln!(
+				w,
+				"   +{:<7} {} {}",
+				format_duration(entry.elapsed),
+				arrow,
+				self.redacted_message(&entry.message)
+			)?;
+		}
+		writeln!(w, "===\n")?;
+		Ok(())
+	}
+}
+
+impl fmt::Display for WsClient {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.to_pretty_string())
+	}
+}
+// endregion: --- Transcript
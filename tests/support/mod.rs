@@ -0,0 +1,13 @@
+//! Local test server helpers (used by the tests not relying on external endpoints).
+
+use axum::Router;
+
+/// Serve the router on a random local port, and return its base URL (e.g., `http://127.0.0.1:1234`).
+pub async fn spawn_server(router: Router) -> String {
+	let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let addr = listener.local_addr().unwrap();
+	tokio::spawn(async move {
+		axum::serve(listener, router).await.unwrap();
+	});
+	format!("http://{addr}")
+}
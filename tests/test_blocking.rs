@@ -0,0 +1,85 @@
+#![cfg(feature = "blocking")]
+
+mod support;
+
+use anyhow::Result;
+use axum::http::header::{COOKIE, SET_COOKIE};
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn test_blocking_client() -> Result<()> {
+	// -- Setup
+	// Note: The server runs on its own (multi thread) runtime, as the blocking client must not be used in one.
+	let server_rt = tokio::runtime::Runtime::new()?;
+	let router = Router::new()
+		.route("/login", post(|| async { ([(SET_COOKIE, "sid=abc; Path=/")], "ok") }))
+		.route(
+			"/whoami",
+			get(|headers: HeaderMap| async move {
+				let cookie = headers.get(COOKIE).and_then(|v| v.to_str().ok()).unwrap_or_default();
+				Json(json!({"cookie": cookie}))
+			}),
+		);
+	let base_url = server_rt.block_on(support::spawn_server(router));
+	let hc = httpc_test::blocking::new_client(base_url)?;
+
+	// -- Exec
+	let login_res = hc.do_post("/login", "")?;
+	let whoami: Value = hc.get("/whoami")?;
+
+	// -- Check
+	login_res.print_sync()?;
+	assert_eq!(login_res.status(), 200);
+	assert_eq!(hc.cookie_value("sid").as_deref(), Some("abc"));
+	assert_eq!(whoami["cookie"], "sid=abc");
+	assert!(hc.new_session().cookie_value("sid").is_none());
+
+	Ok(())
+}
+
+#[test]
+fn test_blocking_rpc_and_poll() -> Result<()> {
+	// -- Setup
+	let server_rt = tokio::runtime::Runtime::new()?;
+	let count = Arc::new(AtomicU32::new(0));
+	let router = Router::new()
+		.route(
+			"/rpc",
+			post(|Json(req): Json<Value>| async move {
+				Json(json!({"jsonrpc": "2.0", "result": req["method"], "id": req["id"]}))
+			}),
+		)
+		.route(
+			"/jobs/1",
+			get(move || {
+				let count = count.clone();
+				async move {
+					match count.fetch_add(1, Ordering::SeqCst) {
+						0 => StatusCode::ACCEPTED,
+						_ => StatusCode::OK,
+					}
+				}
+			}),
+		);
+	let base_url = server_rt.block_on(support::spawn_server(router));
+	let hc = httpc_test::blocking::new_client(base_url)?;
+
+	// -- Exec
+	let method: String = hc.rpc_call("/rpc", "ping", Value::Null)?;
+	let res = hc
+		.poll("/jobs/1")
+		.interval(Duration::from_millis(5))
+		.until(|res| res.status() == 200)?;
+
+	// -- Check
+	assert_eq!(method, "ping");
+	assert_eq!(res.status(), 200);
+
+	Ok(())
+}
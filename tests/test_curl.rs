@@ -0,0 +1,119 @@
+mod support;
+
+use anyhow::Result;
+use axum::http::header::SET_COOKIE;
+use axum::http::{HeaderMap, Method};
+use axum::routing::{any, get, post};
+use axum::{Json, Router};
+use httpc_test::{CurlRequest, PrintOptions, Redaction};
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn test_curl_to_curl() -> Result<()> {
+	// -- Setup
+	let router = Router::new()
+		.route("/login", get(|| async { ([(SET_COOKIE, "sid=abc123; Path=/")], "ok") }))
+		.route("/notes", post(|Json(body): Json<Value>| async move { Json(body) }));
+	let base_url = support::spawn_server(router).await;
+	let hc = httpc_test::new_client(base_url.clone())?;
+	hc.do_get("/login").await?;
+
+	// -- Exec
+	let res = hc
+		.do_post("/notes", json!({"title": "it's", "password": "pwd"}))
+		.await?;
+	let curl = res.to_curl();
+	let raw_curl = res.to_curl_with(&Redaction::none());
+
+	// -- Check
+	assert!(
+		curl.starts_with(&format!("curl -X POST {base_url}/notes \\\n")),
+		"{curl}"
+	);
+	assert!(curl.contains("-H 'content-type: application/json'"), "{curl}");
+	assert!(curl.contains("-b 'sid=***'"), "{curl}");
+	assert!(
+		curl.contains(r#"--data-raw '{"password":"***","title":"it'\''s"}'"#),
+		"{curl}"
+	);
+	assert!(raw_curl.contains("-b sid=abc123"), "{raw_curl}");
+	assert!(raw_curl.contains(r#""password":"pwd""#), "{raw_curl}");
+	assert!(res
+		.to_pretty_string_with(PrintOptions::default().curl(true))
+		.contains("--data-raw"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_curl_parse() -> Result<()> {
+	// -- Exec
+	let req = CurlRequest::parse(
+		r#"curl 'https://example.com/api/items?q=a%20b' \
+  -X PUT \
+  -H 'Content-Type: application/json' \
+  -H "X-Note: \"quoted\"" \
+  -b 'sid=abc; theme=dark' \
+  --data-raw $'{"name":"it\'s","line":"a\nb"}' \
+  --compressed"#,
+	)?;
+
+	// -- Check
+	assert_eq!(req.method, "PUT");
+	assert_eq!(req.url, "https://example.com/api/items?q=a%20b");
+	assert_eq!(req.header("content-type"), Some("application/json"));
+	assert_eq!(req.header("x-note"), Some(r#""quoted""#));
+	assert_eq!(
+		req.cookies,
+		vec![("sid".into(), "abc".into()), ("theme".into(), "dark".into())]
+	);
+	assert_eq!(req.body.as_deref(), Some("{\"name\":\"it's\",\"line\":\"a\nb\"}"));
+	assert!(CurlRequest::parse("curl --unknown-option http://x").is_err());
+	assert!(CurlRequest::parse("curl 'http://x").is_err());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_curl_do_curl() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route(
+		"/echo",
+		any(|method: Method, headers: HeaderMap, body: String| async move {
+			let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).map(String::from);
+			Json(json!({
+				"method": method.as_str(),
+				"content_type": header("content-type"),
+				"authorization": header("authorization"),
+				"cookie": header("cookie"),
+				"body": body,
+			}))
+		}),
+	);
+	let base_url = support::spawn_server(router).await;
+	let hc = httpc_test::new_client(base_url.clone())?;
+
+	// -- Exec
+	let res = hc
+		.do_curl(&format!(
+			"curl '{base_url}/echo' -u 'demo:welcome' -b 'sid=abc' -d 'a=1' -d 'b=2' -H 'accept-encoding: gzip'"
+		))
+		.await?;
+	let json_res = hc.do_curl(r#"curl /echo --json '{"id":1}'"#).await?;
+
+	// -- Check
+	assert_eq!(res.json_value::<String>("/method")?, "POST");
+	assert_eq!(
+		res.json_value::<String>("/content_type")?,
+		"application/x-www-form-urlencoded"
+	);
+	assert_eq!(res.json_value::<String>("/authorization")?, "Basic ZGVtbzp3ZWxjb21l");
+	assert_eq!(res.json_value::<String>("/cookie")?, "sid=abc");
+	assert_eq!(res.json_value::<String>("/body")?, "a=1&b=2");
+	// the cookies of the curl command stay in the session
+	assert_eq!(json_res.json_value::<String>("/cookie")?, "sid=abc");
+	assert_eq!(json_res.json_value::<String>("/content_type")?, "application/json");
+	assert_eq!(json_res.json_value::<String>("/body")?, r#"{"id":1}"#);
+
+	Ok(())
+}
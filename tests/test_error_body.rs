@@ -0,0 +1,87 @@
+mod support;
+
+use anyhow::Result;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use httpc_test::ProblemDetails;
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+struct Ticket {
+	id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiError {
+	error: ApiErrorDetail,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiErrorDetail {
+	code: String,
+}
+
+fn router() -> Router {
+	Router::new()
+		.route("/tickets/1", get(|| async { Json(json!({"id": 1})) }))
+		.route(
+			"/tickets/2",
+			get(|| async {
+				(
+					StatusCode::FORBIDDEN,
+					Json(json!({"error": {"message": "no", "code": "FORBIDDEN"}})),
+				)
+			}),
+		)
+		.route(
+			"/tickets/3",
+			get(|| async {
+				(
+					StatusCode::NOT_FOUND,
+					[(CONTENT_TYPE, "application/problem+json")],
+					json!({"type": "https://example.com/not-found", "title": "Not Found", "status": 404, "ticket_id": 3})
+						.to_string(),
+				)
+			}),
+		)
+}
+
+#[tokio::test]
+async fn test_error_body_get_result() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+
+	// -- Exec
+	let ok = hc.get_result::<Ticket, ApiError>("/tickets/1").await?;
+	let err = hc.get_result::<Ticket, ApiError>("/tickets/2").await?;
+	let problem = hc.get_result::<Ticket, ProblemDetails>("/tickets/3").await?;
+
+	// -- Check
+	assert_eq!(ok.unwrap().id, 1);
+	assert_eq!(err.unwrap_err().error.code, "FORBIDDEN");
+	let problem = problem.unwrap_err();
+	assert_eq!(problem.status, Some(404));
+	assert_eq!(problem.type_uri, "https://example.com/not-found");
+	assert_eq!(problem.extensions["ticket_id"], 3);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_error_body_json_error_as() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+
+	// -- Exec
+	let err_res = hc.do_get("/tickets/2").await?;
+	let ok_res = hc.do_get("/tickets/1").await?;
+
+	// -- Check
+	assert_eq!(err_res.json_error_as::<ApiError>()?.error.code, "FORBIDDEN");
+	assert!(ok_res.json_error_as::<ApiError>().is_err());
+
+	Ok(())
+}
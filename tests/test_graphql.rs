@@ -0,0 +1,66 @@
+mod support;
+
+use anyhow::Result;
+use axum::routing::post;
+use axum::{Json, Router};
+use httpc_test::Error;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+fn router() -> Router {
+	Router::new().route(
+		"/graphql",
+		post(|Json(body): Json<Value>| async move {
+			match body["variables"]["id"].as_i64() {
+				Some(1) => Json(json!({"data": {"user": {"id": 1, "name": "alice"}}})),
+				_ => Json(json!({
+					"data": {"user": null},
+					"errors": [{"message": "User not found", "path": ["user"], "locations": [{"line": 1, "column": 9}]}]
+				})),
+			}
+		}),
+	)
+}
+
+#[derive(Deserialize)]
+struct User {
+	name: String,
+}
+
+const QUERY: &str = "query ($id: Int!) { user(id: $id) { id name } }";
+
+#[tokio::test]
+async fn test_graphql_data() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+
+	// -- Exec
+	let res = hc.graphql("/graphql", QUERY, json!({"id": 1})).await?;
+
+	// -- Check
+	res.expect_no_errors()?;
+	assert_eq!(res.data_value::<String>("/user/name")?, "alice");
+	assert_eq!(res.data_as::<Value>()?["user"]["id"], 1);
+	assert_eq!(res.data_value::<User>("/user")?.name, "alice");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_graphql_errors() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+
+	// -- Exec
+	let res = hc.graphql("/graphql", QUERY, json!({"id": 2})).await?;
+	let err = res.expect_no_errors().unwrap_err();
+
+	// -- Check
+	assert_eq!(res.errors()?[0].message, "User not found");
+	assert!(matches!(err, Error::GraphQLErrors { .. }));
+	let msg = err.to_string();
+	assert!(msg.contains("User not found (path: user) (at 1:9)"));
+	assert!(msg.contains(QUERY));
+
+	Ok(())
+}
@@ -0,0 +1,81 @@
+mod support;
+
+use anyhow::Result;
+use axum::extract::Path;
+use axum::routing::get;
+use axum::Router;
+use httpc_test::Client;
+
+/// Helper making calls without returning the responses.
+async fn fetch_items(hc: &Client, ids: &[u32]) -> Result<()> {
+	for id in ids {
+		hc.do_get(&format!("/items/{id}")).await?;
+	}
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_history_bounded() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route(
+		"/items/{id}",
+		get(|Path(id): Path<u32>| async move { format!("item-{id}") }),
+	);
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?.with_history(2);
+
+	// -- Exec
+	fetch_items(&hc.clone(), &[1, 2, 3]).await?;
+
+	// -- Check
+	let history = hc.history();
+	assert_eq!(history.len(), 2);
+	assert_eq!(history[0].text_body()?, "item-2");
+	assert_eq!(
+		hc.last_response().map(|res| res.text_body()).transpose()?.as_deref(),
+		Some("item-3")
+	);
+	hc.print_history().await?;
+
+	hc.clear_history();
+	assert!(hc.history().is_empty());
+	assert!(hc.last_response().is_none());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_history_disabled_by_default() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route(
+		"/items/{id}",
+		get(|Path(id): Path<u32>| async move { format!("item-{id}") }),
+	);
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?;
+
+	// -- Exec
+	fetch_items(&hc, &[1]).await?;
+
+	// -- Check
+	assert!(hc.history().is_empty());
+	assert!(hc.last_response().is_none());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_history_unbounded() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route(
+		"/items/{id}",
+		get(|Path(id): Path<u32>| async move { format!("item-{id}") }),
+	);
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?.with_history(usize::MAX);
+
+	// -- Exec
+	fetch_items(&hc, &[1, 2, 3]).await?;
+
+	// -- Check
+	assert_eq!(hc.history().len(), 3);
+
+	Ok(())
+}
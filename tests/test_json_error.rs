@@ -0,0 +1,67 @@
+mod support;
+
+use anyhow::Result;
+use axum::routing::get;
+use axum::{Json, Router};
+use httpc_test::Error;
+use serde::Deserialize;
+use serde_json::json;
+
+#[allow(unused)]
+#[derive(Debug, Deserialize)]
+struct Order {
+	id: i64,
+	items: Vec<Item>,
+}
+
+#[allow(unused)]
+#[derive(Debug, Deserialize)]
+struct Item {
+	name: String,
+	price: f64,
+}
+
+#[tokio::test]
+async fn test_json_error_context() -> Result<()> {
+	// -- Setup
+	let app = Router::new().route(
+		"/orders/1",
+		get(|| async {
+			Json(json!({
+				"id": 1,
+				"token": "abc-secret",
+				"items": [{"name": "a", "price": 1.5}, {"name": "b", "price": "free"}]
+			}))
+		}),
+	);
+	let hc = httpc_test::new_client(support::spawn_server(app).await)?;
+
+	// -- Exec
+	let res = hc.do_get("/orders/1").await?;
+	let body_err = res.json_body_as::<Order>().unwrap_err();
+	let value_err = res.json_value::<Vec<Item>>("/items").unwrap_err();
+
+	// -- Check
+	let Error::JsonDeserialize {
+		request,
+		json_pointer,
+		type_name,
+		path,
+		snippet,
+		..
+	} = &body_err
+	else {
+		panic!("expected Error::JsonDeserialize, got {body_err:?}");
+	};
+	assert!(request.starts_with("GET ") && request.ends_with("/orders/1"));
+	assert_eq!(json_pointer, "");
+	assert!(type_name.ends_with("Order"));
+	assert_eq!(path, "items[1].price");
+	assert!(!snippet.contains("abc-secret"), "snippet should be redacted: {snippet}");
+
+	let msg = value_err.to_string();
+	assert!(msg.contains("value at '/items'"), "{msg}");
+	assert!(msg.contains("at path '[1].price'"), "{msg}");
+
+	Ok(())
+}
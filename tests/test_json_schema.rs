@@ -0,0 +1,76 @@
+#![cfg(feature = "json-schema")]
+
+mod support;
+
+use anyhow::Result;
+use axum::routing::get;
+use axum::{Json, Router};
+use httpc_test::Error;
+use serde_json::json;
+
+fn router() -> Router {
+	Router::new().route(
+		"/users",
+		get(|| async {
+			Json(json!([
+				{"id": 1, "name": "Mike", "password": "p@ss"},
+				{"id": "two", "name": "Jen", "password": 123},
+			]))
+		}),
+	)
+}
+
+#[tokio::test]
+async fn test_json_schema_violations() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+	let schema = json!({
+		"$schema": "http://json-schema.org/draft-07/schema#",
+		"type": "array",
+		"items": {
+			"type": "object",
+			"required": ["id", "name"],
+			"properties": {
+				"id": {"type": "integer"},
+				"name": {"type": "string"},
+				"password": {"type": "string"}
+			}
+		}
+	});
+
+	// -- Exec
+	let res = hc.do_get("/users").await?;
+	let err = res.expect_json_schema(&schema).unwrap_err();
+
+	// -- Check
+	let Error::JsonSchemaViolations { violations, .. } = &err else {
+		panic!("expected Error::JsonSchemaViolations, got {err:?}");
+	};
+	let paths: Vec<&str> = violations.iter().map(|v| v.instance_path.as_str()).collect();
+	assert_eq!(paths, vec!["/1/id", "/1/password"]);
+	assert_eq!(violations[0].schema_path, "/items/properties/id/type");
+	assert_eq!(violations[0].instance, json!("two"));
+	// the offending password value is redacted
+	assert_eq!(violations[1].instance, json!("***"));
+	assert!(!err.to_string().contains("123"), "{err}");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_json_schema_file() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+	let schema_path = std::env::temp_dir().join(format!("httpc-test-schema-{}.json", std::process::id()));
+	std::fs::write(&schema_path, json!({"type": "array", "minItems": 2}).to_string())?;
+
+	// -- Exec
+	let res = hc.do_get("/users").await?;
+	let validated = res.validate_json_schema(&schema_path).map(|_| ());
+	std::fs::remove_file(&schema_path)?;
+
+	// -- Check
+	validated?;
+
+	Ok(())
+}
@@ -0,0 +1,28 @@
+mod support;
+
+use anyhow::Result;
+use axum::routing::get;
+use axum::Router;
+
+#[tokio::test]
+async fn test_load_requests() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route("/hello", get(|| async { "hello" }));
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?;
+
+	// -- Exec
+	let report = hc
+		.load_test(4)
+		.requests(40)
+		.run(|hc| async move { hc.do_get("/hello").await })
+		.await;
+
+	// -- Check
+	assert_eq!(report.total(), 40);
+	assert_eq!(report.error_count(), 0);
+	assert_eq!(report.statuses().get(&200), Some(&40));
+	assert!(report.p50() <= report.p99());
+	report.print().await?;
+
+	Ok(())
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "macros")]
+
+mod support;
+
+use anyhow::Result;
+use axum::http::header::{COOKIE, SET_COOKIE};
+use axum::http::HeaderMap;
+use axum::routing::{get, post};
+use axum::Router;
+use httpc_test::Client;
+
+fn router() -> Router {
+	Router::new()
+		.route("/login", post(|| async { ([(SET_COOKIE, "sid=abc; Path=/")], "ok") }))
+		.route(
+			"/whoami",
+			get(|headers: HeaderMap| async move {
+				headers
+					.get(COOKIE)
+					.and_then(|v| v.to_str().ok())
+					.unwrap_or("anonymous")
+					.to_string()
+			}),
+		)
+}
+
+async fn login(hc: &Client) -> Result<()> {
+	hc.do_post("/login", "").await?.error_for_status()?;
+	Ok(())
+}
+
+async fn logout(hc: &Client) -> httpc_test::Result<()> {
+	hc.cookie_store().lock().unwrap().clear();
+	Ok(())
+}
+
+#[httpc_test::test(base_url = support::spawn_server(router()).await, login = login, teardown = logout, history = 5)]
+async fn test_macros_logged_in_client(hc: Client) -> Result<()> {
+	// -- Exec
+	let res = hc.do_get("/whoami").await?;
+
+	// -- Check
+	assert_eq!(res.text_body()?, "sid=abc");
+
+	Ok(())
+}
+
+#[httpc_test::test(base_url = support::spawn_server(router()).await)]
+#[should_panic(expected = "anonymous")]
+async fn test_macros_panic_propagated(hc: Client) {
+	let res = hc.do_get("/whoami").await.unwrap();
+	panic!("{}", res.text_body().unwrap());
+}
@@ -0,0 +1,181 @@
+mod support;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use axum::routing::{get, post};
+use axum::Router;
+use httpc_test::{Middleware, RequestIdMiddleware, RequestParts, Response, TimingLogMiddleware};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+/// Test signing middleware (sum of the body bytes, as a stand-in for an HMAC).
+struct Signer;
+
+#[async_trait]
+impl Middleware for Signer {
+	async fn before_request(&self, parts: &mut RequestParts) -> httpc_test::Result<()> {
+		let body = parts.body_bytes().unwrap_or_default();
+		let signature = body.iter().map(|b| *b as u64).sum::<u64>();
+		parts.set_header("x-signature", &format!("{} {signature}", parts.method))
+	}
+}
+
+/// Test middleware sending the json body as a form (e.g., for a legacy endpoint).
+struct FormBody;
+
+#[async_trait]
+impl Middleware for FormBody {
+	async fn before_request(&self, parts: &mut RequestParts) -> httpc_test::Result<()> {
+		let Some(body) = parts.body_bytes() else {
+			return Ok(());
+		};
+		let value: serde_json::Value = serde_json::from_slice(&body)?;
+		let name = value["name"].as_str().unwrap_or_default();
+		parts.set_text_body(format!("name={name}"), "application/x-www-form-urlencoded");
+		Ok(())
+	}
+}
+
+/// Test middleware setting an invalid header value.
+struct BadHeader;
+
+#[async_trait]
+impl Middleware for BadHeader {
+	async fn before_request(&self, parts: &mut RequestParts) -> httpc_test::Result<()> {
+		parts.set_header("x-bad", "line\nbreak")
+	}
+}
+
+/// Test middleware recording its hook calls.
+struct Recorder {
+	name: &'static str,
+	calls: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl Middleware for Recorder {
+	async fn before_request(&self, _parts: &mut RequestParts) -> httpc_test::Result<()> {
+		self.calls.lock().unwrap().push(format!("{} before", self.name));
+		Ok(())
+	}
+
+	async fn after_response(&self, res: &Response) -> httpc_test::Result<()> {
+		self.calls
+			.lock()
+			.unwrap()
+			.push(format!("{} after {}", self.name, res.status()));
+		Ok(())
+	}
+}
+
+fn header(headers: &HeaderMap, name: &str) -> String {
+	headers
+		.get(name)
+		.and_then(|v| v.to_str().ok())
+		.unwrap_or_default()
+		.to_string()
+}
+
+#[tokio::test]
+async fn test_middleware_signing_and_request_id() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route(
+		"/echo",
+		post(|headers: HeaderMap, body: String| async move {
+			let signature = body.bytes().map(|b| b as u64).sum::<u64>();
+			let valid = header(&headers, "x-signature") == format!("POST {signature}");
+			axum::Json(json!({
+				"valid": valid,
+				"request_id": header(&headers, "x-request-id"),
+				"trace_id": header(&headers, "x-trace-id"),
+			}))
+		}),
+	);
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?
+		.with_middleware(RequestIdMiddleware::default())
+		.with_middleware(RequestIdMiddleware::default().header_name("x-trace-id")?)
+		.with_middleware(TimingLogMiddleware)
+		.with_middleware(Signer);
+
+	// -- Exec
+	let res = hc.do_post("/echo", json!({"name": "ticket-01"})).await?;
+
+	// -- Check
+	assert!(res.json_value::<bool>("/valid")?);
+	let request_id = res.json_value::<String>("/request_id")?;
+	assert_eq!(request_id.len(), 36);
+	assert_eq!(&request_id[14..15], "4");
+	assert_ne!(res.json_value::<String>("/trace_id")?, request_id);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_order() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route("/ping", get(|| async { "pong" }));
+	let calls = Arc::new(Mutex::new(Vec::new()));
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?
+		.with_middleware(Recorder {
+			name: "a",
+			calls: calls.clone(),
+		})
+		.with_middleware(Recorder {
+			name: "b",
+			calls: calls.clone(),
+		});
+
+	// -- Exec
+	hc.do_get("/ping").await?;
+	hc.do_get("/not-found").await?;
+
+	// -- Check
+	let calls = calls.lock().unwrap().clone();
+	assert_eq!(
+		calls,
+		vec![
+			"a before",
+			"b before",
+			"a after 200 OK",
+			"b after 200 OK",
+			"a before",
+			"b before",
+			"a after 404 Not Found",
+			"b after 404 Not Found",
+		]
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_text_body_and_invalid_header() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route(
+		"/form",
+		post(|headers: HeaderMap, body: String| async move {
+			let signature = body.bytes().map(|b| b as u64).sum::<u64>();
+			let valid = header(&headers, "x-signature") == format!("POST {signature}");
+			format!("{} {body} {valid}", header(&headers, "content-type"))
+		}),
+	);
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?
+		.with_middleware(FormBody)
+		.with_middleware(Signer);
+
+	// -- Exec
+	let res = hc.do_post("/form", json!({"name": "ticket-01"})).await?;
+	let bad_value_err = hc.clone().with_middleware(BadHeader).do_get("/form").await.err();
+	let bad_name_err = RequestIdMiddleware::default().header_name("x request id").err();
+
+	// -- Check
+	assert_eq!(
+		res.text_body()?,
+		"application/x-www-form-urlencoded name=ticket-01 true"
+	);
+	assert!(matches!(bad_value_err, Some(httpc_test::Error::InvalidHeader { .. })));
+	assert!(matches!(bad_name_err, Some(httpc_test::Error::InvalidHeader { .. })));
+
+	Ok(())
+}
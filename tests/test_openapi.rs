@@ -0,0 +1,152 @@
+#![cfg(feature = "openapi")]
+
+mod support;
+
+use anyhow::Result;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use httpc_test::{Error, OpenApi};
+use serde_json::json;
+
+const SPEC: &str = r##"
+openapi: 3.1.0
+info: { title: Users, version: "1.0" }
+servers:
+  - url: http://localhost/api
+paths:
+  /users/{id}:
+    get:
+      responses:
+        "200":
+          description: The user
+          content:
+            application/json:
+              schema: { $ref: "#/components/schemas/User" }
+        "404":
+          description: Not found
+components:
+  schemas:
+    User:
+      type: object
+      required: [id, name]
+      properties:
+        id: { type: integer }
+        name: { type: string }
+"##;
+
+fn router() -> Router {
+	Router::new()
+		.route(
+			"/api/users/{id}",
+			get(|Path(id): Path<i64>| async move {
+				match id {
+					1 => (StatusCode::OK, Json(json!({"id": 1, "name": "Mike"}))),
+					2 => (StatusCode::OK, Json(json!({"id": "two"}))),
+					3 => (StatusCode::INTERNAL_SERVER_ERROR, Json(json!({"error": "boom"}))),
+					_ => (StatusCode::NOT_FOUND, Json(json!({}))),
+				}
+			}),
+		)
+		.route("/api/undocumented", get(|| async { "hello" }))
+}
+
+#[tokio::test]
+async fn test_openapi_conform() -> Result<()> {
+	// -- Setup
+	let base_url = support::spawn_server(router()).await;
+	let hc = httpc_test::new_client(base_url)?.with_openapi(OpenApi::from_yaml(SPEC)?);
+
+	// -- Exec & Check
+	assert_eq!(hc.do_get("/api/users/1").await?.status(), 200);
+	assert_eq!(hc.do_get("/api/users/9").await?.status(), 404);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_openapi_violations() -> Result<()> {
+	// -- Setup
+	let base_url = support::spawn_server(router()).await;
+	let hc = httpc_test::new_client(base_url)?.with_openapi(OpenApi::from_yaml(SPEC)?);
+
+	// -- Exec
+	let body_err = hc.do_get("/api/users/2").await.unwrap_err();
+	let status_err = hc.do_get("/api/users/3").await.unwrap_err();
+	let path_err = hc.do_get("/api/undocumented").await.unwrap_err();
+
+	// -- Check
+	let Error::OpenApiViolations { violations, response } = &body_err else {
+		panic!("expected Error::OpenApiViolations, got {body_err:?}");
+	};
+	assert_eq!(response.status(), 200);
+	let paths: Vec<_> = violations.iter().filter_map(|v| v.instance_path.as_deref()).collect();
+	assert!(paths.contains(&"/id"), "{violations:?}");
+	assert!(paths.contains(&""), "missing 'name' should be reported: {violations:?}");
+
+	assert!(status_err
+		.to_string()
+		.contains("status 500 Internal Server Error is not documented"));
+	assert!(path_err.to_string().contains("no path matching '/api/undocumented'"));
+
+	Ok(())
+}
+
+const SPEC_3_0: &str = r##"
+openapi: 3.0.3
+info: { title: Users, version: "1.0" }
+paths:
+  /api/users/{id}:
+    get:
+      responses:
+        "200":
+          description: The user
+          content:
+            application/json:
+              schema: { $ref: "#/components/schemas/User" }
+components:
+  schemas:
+    User:
+      type: object
+      required: [id, name, nickname, role]
+      properties:
+        id: { type: integer }
+        name: { type: string }
+        nickname: { type: string, nullable: true }
+        role: { type: string, enum: [admin, user], nullable: true }
+"##;
+
+#[tokio::test]
+async fn test_openapi_3_0_nullable() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route(
+		"/api/users/{id}",
+		get(|Path(id): Path<i64>| async move {
+			match id {
+				1 => Json(json!({"id": 1, "name": "Mike", "nickname": null, "role": null})),
+				2 => Json(json!({"id": 2, "name": "Jen", "nickname": "jj", "role": "admin"})),
+				_ => Json(json!({"id": 3, "name": null, "nickname": 3, "role": null})),
+			}
+		}),
+	);
+	let base_url = support::spawn_server(router).await;
+	let hc = httpc_test::new_client(base_url)?.with_openapi(OpenApi::from_yaml(SPEC_3_0)?);
+
+	// -- Exec
+	let null_res = hc.do_get("/api/users/1").await?;
+	let value_res = hc.do_get("/api/users/2").await?;
+	let err = hc.do_get("/api/users/3").await.unwrap_err();
+
+	// -- Check
+	assert_eq!(null_res.status(), 200);
+	assert_eq!(value_res.status(), 200);
+	let Error::OpenApiViolations { violations, .. } = &err else {
+		panic!("expected Error::OpenApiViolations, got {err:?}");
+	};
+	let mut paths: Vec<_> = violations.iter().filter_map(|v| v.instance_path.as_deref()).collect();
+	paths.sort();
+	assert_eq!(paths, vec!["/name", "/nickname"], "{violations:?}");
+
+	Ok(())
+}
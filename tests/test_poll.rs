@@ -0,0 +1,74 @@
+mod support;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use httpc_test::{Error, Redaction};
+use serde_json::json;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_poll_until_done() -> Result<()> {
+	// -- Setup
+	let count = Arc::new(AtomicU32::new(0));
+	let router = Router::new().route(
+		"/jobs/1",
+		get(move || {
+			let count = count.clone();
+			async move {
+				match count.fetch_add(1, Ordering::SeqCst) {
+					0 | 1 => (StatusCode::ACCEPTED, Json(json!({"state": "pending"}))),
+					_ => (StatusCode::OK, Json(json!({"state": "done"}))),
+				}
+			}
+		}),
+	);
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?;
+
+	// -- Exec
+	let res = hc
+		.poll("/jobs/1")
+		.interval(Duration::from_millis(5))
+		.until(|res| res.status() == 200)
+		.await?;
+
+	// -- Check
+	assert_eq!(res.json_value::<String>("/state")?, "done");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_poll_timeout() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route("/jobs/2", get(|| async { StatusCode::ACCEPTED }));
+	let redaction = Redaction::default().pattern("token=([^&]+)")?;
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?.with_redaction(redaction);
+
+	// -- Exec
+	let err = hc
+		.poll("/jobs/2?token=secret-01")
+		.interval(Duration::from_millis(10))
+		.timeout(Duration::from_millis(50))
+		.until(|res| res.status() == 200)
+		.await
+		.unwrap_err();
+
+	// -- Check
+	let message = err.to_string();
+	assert!(message.contains("http://127.0.0.1:"), "{message}");
+	assert!(!message.contains("secret-01"), "{message}");
+	let Error::PollTimeout {
+		calls, last_response, ..
+	} = err
+	else {
+		panic!("expected Error::PollTimeout, was {err:?}");
+	};
+	assert!(calls > 1);
+	assert_eq!(last_response.status(), 202);
+
+	Ok(())
+}
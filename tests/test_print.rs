@@ -0,0 +1,60 @@
+mod support;
+
+use anyhow::Result;
+use axum::routing::get;
+use axum::{Json, Router};
+use httpc_test::PrintOptions;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_print_write_to() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route("/ticket", get(|| async { Json(json!({"subject": "ticket-01"})) }));
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?;
+	let res = hc.do_get("/ticket").await?;
+
+	// -- Exec
+	let mut buf: Vec<u8> = Vec::new();
+	res.write_to(&mut buf, PrintOptions::default())?;
+	let full = String::from_utf8(buf)?;
+	let no_body = res.to_pretty_string_with(PrintOptions::default().body(false));
+
+	// -- Check
+	assert!(full.contains("ticket-01"));
+	assert!(full.contains("Response Body"));
+	assert!(!no_body.contains("ticket-01"));
+	assert_eq!(res.to_string(), full);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_print_options_filtering() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route(
+		"/tickets",
+		get(|| async { Json(json!({"data": {"items": [1, 2, 3, 4, 5]}, "meta": "meta-01"})) }),
+	);
+	let options = PrintOptions::default()
+		.header_deny(["Content-Length"])
+		.json_pointer("/data")
+		.max_array_items(2)
+		.compact(true);
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?.with_print_options(options);
+
+	// -- Exec
+	let res = hc.do_get("/tickets").await?;
+	let printed = res.to_pretty_string();
+	let truncated = res.to_pretty_string_with(PrintOptions::default().body_max_len(10));
+
+	// -- Check
+	assert!(printed.contains("... (3 more items)"));
+	// compact json body, on one line
+	assert!(printed.lines().any(|line| line.contains("\"items\":[1,2,") && line.ends_with("]}")));
+	assert!(!printed.contains("meta-01"));
+	assert!(!printed.contains("content-length"));
+	assert!(printed.contains("content-type"));
+	assert!(truncated.contains("more chars)"));
+
+	Ok(())
+}
@@ -0,0 +1,56 @@
+mod support;
+
+use anyhow::Result;
+use axum::http::header::SET_COOKIE;
+use axum::routing::get;
+use axum::{Json, Router};
+use httpc_test::Redaction;
+use serde_json::json;
+
+fn router() -> Router {
+	Router::new().route(
+		"/login",
+		get(|| async {
+			(
+				[(SET_COOKIE, "auth-token=secret-cookie-01; Path=/")],
+				Json(json!({"user": {"name": "alice", "access_token": "secret-token-01", "ssn": "123-45"}})),
+			)
+		}),
+	)
+}
+
+#[tokio::test]
+async fn test_redaction_default() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+
+	// -- Exec
+	let printed = hc.do_get("/login").await?.to_pretty_string();
+
+	// -- Check
+	assert!(printed.contains("alice"));
+	assert!(printed.contains("123-45"));
+	assert!(!printed.contains("secret-token-01"));
+	assert!(!printed.contains("secret-cookie-01"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_redaction_custom() -> Result<()> {
+	// -- Setup
+	let redaction = Redaction::none()
+		.json_pointer("/user/ssn")
+		.pattern(r"secret-(token)-\d+")?;
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?.with_redaction(redaction);
+
+	// -- Exec
+	let printed = hc.do_get("/login").await?.to_pretty_string();
+
+	// -- Check
+	assert!(!printed.contains("123-45"));
+	assert!(printed.contains("secret-***-01"));
+	assert!(printed.contains("secret-cookie-01"));
+
+	Ok(())
+}
@@ -0,0 +1,133 @@
+mod support;
+
+use anyhow::Result;
+use axum::http::header::{AUTHORIZATION, COOKIE, LOCATION, SET_COOKIE};
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::{get, post};
+use axum::Router;
+use httpc_test::{Middleware, RequestParts};
+
+fn router() -> Router {
+	Router::new()
+		.route(
+			"/login",
+			post(|| async {
+				(
+					StatusCode::FOUND,
+					[(SET_COOKIE, "auth=xyz; Path=/"), (LOCATION, "/start")],
+				)
+			}),
+		)
+		.route(
+			"/start",
+			get(|| async { (StatusCode::TEMPORARY_REDIRECT, [(LOCATION, "/home")]) }),
+		)
+		.route(
+			"/home",
+			get(|headers: HeaderMap| async move {
+				headers
+					.get(COOKIE)
+					.and_then(|v| v.to_str().ok())
+					.unwrap_or_default()
+					.to_string()
+			}),
+		)
+}
+
+#[tokio::test]
+async fn test_redirect_chain() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+
+	// -- Exec
+	let res = hc.do_post("/login", "").await?;
+
+	// -- Check
+	assert_eq!(res.status(), 200);
+	assert_eq!(res.text_body()?, "auth=xyz");
+	assert!(res.request_url().ends_with("/login"));
+	assert!(res.final_url().ends_with("/home"));
+
+	let redirects = res.redirects();
+	assert_eq!(redirects.len(), 2);
+	assert_eq!(redirects[0].status, StatusCode::FOUND);
+	assert!(redirects[0].url.ends_with("/login"));
+	assert!(redirects[0].location.ends_with("/start"));
+	assert_eq!(redirects[0].cookies[0].name, "auth");
+	assert_eq!(redirects[1].status, StatusCode::TEMPORARY_REDIRECT);
+	assert!(redirects[1].location.ends_with("/home"));
+	assert!(res.to_pretty_string().contains("Redirects"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_redirect_manual() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?.with_follow_redirects(false);
+
+	// -- Exec
+	let res = hc.do_post("/login", "").await?;
+
+	// -- Check
+	assert_eq!(res.status(), StatusCode::FOUND);
+	assert_eq!(res.header("location").as_deref(), Some("/start"));
+	assert!(res.redirects().is_empty());
+	assert!(res.final_url().ends_with("/login"));
+	// the cookies of the 3xx response are still stored
+	assert_eq!(hc.cookie_value("auth").as_deref(), Some("xyz"));
+
+	Ok(())
+}
+
+/// Test middleware adding a bearer token to each request.
+struct BearerAuth;
+
+#[async_trait::async_trait]
+impl Middleware for BearerAuth {
+	async fn before_request(&self, parts: &mut RequestParts) -> httpc_test::Result<()> {
+		parts.set_header("authorization", "Bearer SECRET")
+	}
+}
+
+#[tokio::test]
+async fn test_redirect_cross_origin_headers() -> Result<()> {
+	// -- Setup
+	let echo = || {
+		get(|headers: HeaderMap| async move {
+			// Note: `-` when the header is absent (not to be confused with an empty value).
+			let header = |name| headers.get(name).and_then(|v| v.to_str().ok()).unwrap_or("-").to_string();
+			format!("auth={} trace={}", header(AUTHORIZATION.as_str()), header("x-trace"))
+		})
+	};
+	let other_url = support::spawn_server(Router::new().route("/echo", echo())).await;
+	let other_location = format!("{}/echo", other_url.replace("127.0.0.1", "localhost"));
+	let router = Router::new()
+		.route(
+			"/away",
+			get(move || async move { (StatusCode::TEMPORARY_REDIRECT, [(LOCATION, other_location)]) }),
+		)
+		.route(
+			"/stay",
+			get(|| async { (StatusCode::TEMPORARY_REDIRECT, [(LOCATION, "/echo")]) }),
+		)
+		.route("/echo", echo());
+	let base_url = support::spawn_server(router).await;
+	let hc = httpc_test::new_client(base_url.as_str())?;
+	let hc_auth = httpc_test::new_client(base_url.as_str())?.with_middleware(BearerAuth);
+
+	// -- Exec
+	let curl = "curl /away -H 'Authorization: Bearer SECRET' -H 'X-Trace: t1'";
+	let curl_res = hc.do_curl(curl).await?;
+	let curl_stay_res = hc.do_curl(&curl.replace("/away", "/stay")).await?;
+	let middleware_res = hc_auth.do_get("/away").await?;
+
+	// -- Check
+	assert_eq!(curl_res.text_body()?, "auth=- trace=t1");
+	assert!(!curl_res.to_curl().contains("authorization"));
+	assert!(curl_res.final_url().contains("localhost"));
+	assert_eq!(curl_stay_res.text_body()?, "auth=Bearer SECRET trace=t1");
+	assert_eq!(middleware_res.text_body()?, "auth=- trace=-");
+
+	Ok(())
+}
@@ -0,0 +1,91 @@
+mod support;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::Router;
+use httpc_test::RetryPolicy;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_retry_until_success() -> Result<()> {
+	// -- Setup
+	let count = Arc::new(AtomicU32::new(0));
+	let router = Router::new().route(
+		"/flaky",
+		get(move || {
+			let count = count.clone();
+			async move {
+				match count.fetch_add(1, Ordering::SeqCst) {
+					0 | 1 => (StatusCode::SERVICE_UNAVAILABLE, "not yet"),
+					_ => (StatusCode::OK, "ok"),
+				}
+			}
+		}),
+	);
+	let base_url = support::spawn_server(router).await;
+	let policy = RetryPolicy::new(5).backoff(Duration::from_millis(1), Duration::from_millis(5));
+	let hc = httpc_test::new_client(base_url)?.with_retry_policy(policy);
+
+	// -- Exec
+	let res = hc.do_get("/flaky").await?;
+
+	// -- Check
+	assert_eq!(res.status(), 200);
+	let statuses: Vec<_> = res.attempts().iter().map(|a| a.status.map(|s| s.as_u16())).collect();
+	assert_eq!(statuses, vec![Some(503), Some(503), Some(200)]);
+	assert!(res.attempts()[0].delay.is_some());
+	assert!(res.attempts()[2].delay.is_none());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_gives_up_after_max_attempts() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route("/down", get(|| async { (StatusCode::BAD_GATEWAY, "down") }));
+	let base_url = support::spawn_server(router).await;
+	let policy = RetryPolicy::new(2).backoff(Duration::from_millis(1), Duration::from_millis(1));
+	let hc = httpc_test::new_client(base_url)?.with_retry_policy(policy);
+
+	// -- Exec
+	let res = hc.do_get("/down").await?;
+
+	// -- Check
+	assert_eq!(res.status(), 502);
+	assert_eq!(res.attempts().len(), 2);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_after_capped() -> Result<()> {
+	// -- Setup
+	let count = Arc::new(AtomicU32::new(0));
+	let router = Router::new().route(
+		"/busy",
+		get(move || {
+			let count = count.clone();
+			async move {
+				match count.fetch_add(1, Ordering::SeqCst) {
+					0 => (StatusCode::SERVICE_UNAVAILABLE, [("retry-after", "3600")], "busy"),
+					_ => (StatusCode::OK, [("retry-after", "0")], "ok"),
+				}
+			}
+		}),
+	);
+	let base_url = support::spawn_server(router).await;
+	let policy = RetryPolicy::new(2).max_retry_after(Duration::from_millis(20));
+	let hc = httpc_test::new_client(base_url)?.with_retry_policy(policy);
+
+	// -- Exec
+	let res = hc.do_get("/busy").await?;
+
+	// -- Check
+	assert_eq!(res.status(), 200);
+	assert_eq!(res.attempts()[0].delay, Some(Duration::from_millis(20)));
+
+	Ok(())
+}
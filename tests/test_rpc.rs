@@ -0,0 +1,109 @@
+mod support;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use axum::response::Html;
+use axum::routing::post;
+use axum::{Json, Router};
+use httpc_test::Error;
+use serde_json::{json, Value};
+
+fn handle(req: &Value) -> Value {
+	let id = req["id"].clone();
+	match req["method"].as_str() {
+		Some("add") => {
+			let sum: i64 = req["params"].as_array().unwrap().iter().filter_map(Value::as_i64).sum();
+			json!({"jsonrpc": "2.0", "result": sum, "id": id})
+		}
+		Some("bad_id") => json!({"jsonrpc": "2.0", "result": 0, "id": 999}),
+		// strict server: params must be an array or object, if present
+		Some("ping") => match req.get("params") {
+			None => json!({"jsonrpc": "2.0", "result": "pong", "id": id}),
+			Some(_) => json!({"jsonrpc": "2.0", "error": {"code": -32602, "message": "Invalid params"}, "id": id}),
+		},
+		_ => json!({"jsonrpc": "2.0", "error": {"code": -32601, "message": "Method not found"}, "id": id}),
+	}
+}
+
+fn router() -> Router {
+	Router::new().route(
+		"/rpc",
+		post(|Json(body): Json<Value>| async move {
+			match body {
+				// reverse the batch order, to check the id correlation
+				Value::Array(reqs) => Json(Value::Array(reqs.iter().rev().map(handle).collect())),
+				req => Json(handle(&req)),
+			}
+		}),
+	)
+}
+
+#[tokio::test]
+async fn test_rpc_call() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+
+	// -- Exec
+	let sum = hc.rpc_call::<i64>("/rpc", "add", json!([1, 2, 3])).await?;
+	let not_found = hc.rpc_call::<Value>("/rpc", "nope", json!([])).await;
+	let bad_id = hc.rpc_call::<Value>("/rpc", "bad_id", json!([])).await;
+	let pong = hc.rpc_call::<String>("/rpc", "ping", Value::Null).await?;
+
+	// -- Check
+	assert_eq!(sum, 6);
+	assert!(matches!(not_found, Err(Error::Rpc(err)) if err.code == -32601));
+	assert!(matches!(bad_id, Err(Error::RpcIdMismatch { .. })));
+	assert_eq!(pong, "pong");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_rpc_batch() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+
+	// -- Exec
+	let results = hc
+		.rpc_batch(
+			"/rpc",
+			[("add", json!([1, 1])), ("nope", json!([])), ("add", json!([2, 2]))],
+		)
+		.await?;
+
+	// -- Check
+	assert_eq!(results.len(), 3);
+	assert_eq!(results[0], Ok(json!(2)));
+	assert_eq!(results[1].as_ref().unwrap_err().message, "Method not found");
+	assert_eq!(results[2], Ok(json!(4)));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_rpc_http_error_without_json() -> Result<()> {
+	// -- Setup
+	let router = Router::new()
+		.route(
+			"/rpc",
+			post(|| async { (StatusCode::BAD_GATEWAY, Html("<h1>502 Bad Gateway</h1>")) }),
+		)
+		.route("/rpc-text", post(|| async { "not json" }));
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?;
+
+	// -- Exec
+	let call_err = hc.rpc_call::<Value>("/rpc", "add", json!([1])).await.unwrap_err();
+	let batch_err = hc.rpc_batch("/rpc", [("add", json!([1]))]).await.unwrap_err();
+	let text_err = hc.rpc_call::<Value>("/rpc-text", "add", json!([1])).await.unwrap_err();
+
+	// -- Check
+	assert!(
+		matches!(&call_err, Error::HttpStatus { status, .. } if *status == 502),
+		"{call_err}"
+	);
+	assert!(call_err.to_string().contains("502 Bad Gateway"));
+	assert!(matches!(batch_err, Error::HttpStatus { .. }));
+	assert!(matches!(&text_err, Error::RpcInvalidResponse { reason } if reason.contains("status 200")));
+
+	Ok(())
+}
@@ -0,0 +1,125 @@
+mod support;
+
+use anyhow::Result;
+use axum::extract::Path;
+use axum::http::header::{AUTHORIZATION, CONTENT_TYPE, COOKIE, LOCATION, SET_COOKIE};
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::{get, post};
+use axum::Router;
+
+fn router() -> Router {
+	Router::new()
+		.route(
+			"/login/{user}",
+			post(|Path(user): Path<String>| async move {
+				(
+					StatusCode::FOUND,
+					[(SET_COOKIE, format!("user={user}; Path=/")), (LOCATION, "/whoami".to_string())],
+				)
+			}),
+		)
+		.route(
+			"/whoami",
+			get(|headers: HeaderMap| async move {
+				headers
+					.get(COOKIE)
+					.and_then(|v| v.to_str().ok())
+					.unwrap_or("anonymous")
+					.to_string()
+			}),
+		)
+}
+
+#[tokio::test]
+async fn test_session_isolated_cookies() -> Result<()> {
+	// -- Setup
+	let alice = httpc_test::new_client(support::spawn_server(router()).await)?.with_session_name("alice");
+	let bob = alice.new_session().with_session_name("bob");
+
+	// -- Exec
+	let res = alice.do_post("/login/alice", "").await?;
+	let bob_res = bob.do_get("/whoami").await?;
+
+	// -- Check
+	// login redirect followed, with the cookie set by the redirect response
+	assert_eq!(res.status(), 200);
+	assert_eq!(res.text_body()?, "user=alice");
+	assert_eq!(res.session_name(), Some("alice"));
+	assert_eq!(alice.cookie_value("user").as_deref(), Some("alice"));
+	// bob session does not see alice cookies
+	assert_eq!(bob_res.text_body()?, "anonymous");
+	assert_eq!(bob_res.session_name(), Some("bob"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_session_fork_copies_cookies() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+	hc.do_post("/login/alice", "").await?;
+
+	// -- Exec
+	let forked = hc.fork_session();
+	let forked_res = forked.do_get("/whoami").await?;
+	forked.do_post("/login/carol", "").await?;
+
+	// -- Check
+	assert_eq!(forked_res.text_body()?, "user=alice");
+	assert_eq!(hc.do_get("/whoami").await?.text_body()?, "user=alice");
+	assert_eq!(forked.do_get("/whoami").await?.text_body()?, "user=carol");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_session_redirect_cross_origin_no_credentials() -> Result<()> {
+	// -- Setup
+	let echo = || {
+		get(|headers: HeaderMap| async move {
+			// Note: `-` when the header is absent (not to be confused with an empty value).
+			let header = |name| headers.get(name).and_then(|v| v.to_str().ok()).unwrap_or("-").to_string();
+			format!(
+				"auth={} cookie={} content-type={}",
+				header(AUTHORIZATION),
+				header(COOKIE),
+				header(CONTENT_TYPE)
+			)
+		})
+	};
+	let other_url = support::spawn_server(Router::new().route("/echo", echo())).await;
+	// Note: Another host, as the cookies are not port specific.
+	let other_location = format!("{}/echo", other_url.replace("127.0.0.1", "localhost"));
+	let router = Router::new()
+		.route(
+			"/away",
+			post(move || async move { (StatusCode::FOUND, [(LOCATION, other_location)]) }),
+		)
+		.route(
+			"/stay",
+			post(|| async {
+				(
+					StatusCode::SEE_OTHER,
+					[(SET_COOKIE, "user=alice; Path=/"), (LOCATION, "/echo")],
+				)
+			}),
+		)
+		.route("/echo", echo());
+	let mut default_headers = reqwest::header::HeaderMap::new();
+	default_headers.insert(AUTHORIZATION, "Bearer SECRET".parse()?);
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?.with_default_headers(default_headers);
+
+	// -- Exec
+	let same_origin_res = hc.do_post("/stay", serde_json::json!({"id": 1})).await?;
+	let cross_origin_res = hc.do_post("/away", serde_json::json!({"id": 1})).await?;
+
+	// -- Check
+	assert_eq!(
+		same_origin_res.text_body()?,
+		"auth=Bearer SECRET cookie=user=alice content-type=-"
+	);
+	assert_eq!(cross_origin_res.text_body()?, "auth=- cookie=- content-type=-");
+	assert!(!cross_origin_res.to_curl().contains("authorization"));
+
+	Ok(())
+}
@@ -0,0 +1,53 @@
+mod support;
+
+use anyhow::Result;
+use axum::body::Body;
+use axum::http::header::CONTENT_TYPE;
+use axum::routing::get;
+use axum::Router;
+use futures_util::stream::{self, StreamExt};
+use httpc_test::Error;
+use serde_json::Value;
+use std::convert::Infallible;
+use std::time::Duration;
+
+fn router() -> Router {
+	Router::new().route(
+		"/notifications",
+		get(|| async {
+			// events split across chunks, then the stream stays open
+			let chunks = [
+				": welcome\n\nevent: notif\nid: 1\ndata: {\"msg\":",
+				" \"hello\"}\n\n",
+				"data: line-1\r\ndata: line-2\r\n\r\n",
+			];
+			let body = stream::iter(chunks.map(|c| Ok::<_, Infallible>(c.to_string()))).chain(stream::pending());
+			([(CONTENT_TYPE, "text/event-stream")], Body::from_stream(body))
+		}),
+	)
+}
+
+#[tokio::test]
+async fn test_sse_events() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+
+	// -- Exec
+	let res = hc.do_get_stream("/notifications").await?;
+	assert_eq!(res.status(), 200);
+	let mut sse = res.into_sse();
+	let first = sse.next_event_within(Duration::from_secs(2)).await?;
+	let second = sse.next_event_within(Duration::from_secs(2)).await?;
+	let third = sse.next_event_within(Duration::from_millis(100)).await;
+
+	// -- Check
+	assert_eq!(first.event, "notif");
+	assert_eq!(first.id.as_deref(), Some("1"));
+	assert_eq!(first.data_as::<Value>()?["msg"], "hello");
+	assert_eq!(second.event, "message");
+	assert_eq!(second.data, "line-1\nline-2");
+	assert_eq!(second.id.as_deref(), Some("1"));
+	assert!(matches!(third, Err(Error::SseTimeout { .. })));
+
+	Ok(())
+}
@@ -0,0 +1,48 @@
+mod support;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use httpc_test::Error;
+use serde_json::{json, Value};
+
+fn router() -> Router {
+	Router::new()
+		.route("/ok", get(|| async { Json(json!({"id": 1})) }))
+		.route(
+			"/fail",
+			get(|| async {
+				(
+					StatusCode::INTERNAL_SERVER_ERROR,
+					Json(json!({"error": {"message": "db-down-01"}})),
+				)
+			}),
+		)
+}
+
+#[tokio::test]
+async fn test_status_error_for_status() -> Result<()> {
+	// -- Setup
+	let base_url = support::spawn_server(router()).await;
+	let hc = httpc_test::new_client(&base_url)?.with_error_for_status(true);
+	let hc_lenient = httpc_test::new_client(&base_url)?;
+
+	// -- Exec
+	let ok = hc.get::<Value>("/ok").await?;
+	let err = hc.get::<Value>("/fail").await.unwrap_err();
+	let lenient = hc_lenient.get::<Value>("/fail").await?;
+
+	// -- Check
+	assert_eq!(ok["id"], 1);
+	let msg = err.to_string();
+	let Error::HttpStatus { status, response } = err else {
+		panic!("expected Error::HttpStatus");
+	};
+	assert_eq!(status, 500);
+	assert_eq!(response.json_value::<String>("/error/message")?, "db-down-01");
+	assert!(msg.contains("db-down-01"));
+	assert_eq!(lenient["error"]["message"], "db-down-01");
+
+	Ok(())
+}
@@ -0,0 +1,33 @@
+mod support;
+
+use anyhow::Result;
+use axum::routing::get;
+use axum::Router;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_timing_elapsed() -> Result<()> {
+	// -- Setup
+	let router = Router::new().route(
+		"/slow",
+		get(|| async {
+			tokio::time::sleep(Duration::from_millis(50)).await;
+			"slow"
+		}),
+	);
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?;
+
+	// -- Exec
+	let res = hc.do_get("/slow").await?;
+
+	// -- Check
+	assert!(res.elapsed() >= Duration::from_millis(50));
+	assert!(res.timing().ttfb >= Duration::from_millis(50));
+	assert!(res.expect_elapsed_below(Duration::from_secs(10)).is_ok());
+	assert!(matches!(
+		res.expect_elapsed_below(Duration::from_millis(10)),
+		Err(httpc_test::Error::ElapsedAboveLimit { .. })
+	));
+
+	Ok(())
+}
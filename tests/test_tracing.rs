@@ -0,0 +1,77 @@
+#![cfg(feature = "tracing")]
+
+mod support;
+
+use anyhow::Result;
+use axum::http::HeaderMap;
+use axum::routing::post;
+use axum::Router;
+use httpc_test::TraceparentMiddleware;
+use serde_json::json;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+/// Log writer capturing the trace output of the test.
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for LogBuffer {
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		self.0.lock().unwrap().extend_from_slice(buf);
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
+
+#[tokio::test]
+async fn test_tracing_span_and_traceparent() -> Result<()> {
+	// -- Setup
+	let logs = LogBuffer::default();
+	let writer = logs.clone();
+	let subscriber = tracing_subscriber::fmt()
+		.with_max_level(tracing::Level::TRACE)
+		.with_ansi(false)
+		.with_writer(move || writer.clone())
+		.finish();
+	let _guard = tracing::subscriber::set_default(subscriber);
+
+	let router = Router::new().route(
+		"/login",
+		post(|headers: HeaderMap| async move {
+			let traceparent = headers
+				.get("traceparent")
+				.and_then(|v| v.to_str().ok())
+				.unwrap_or_default();
+			axum::Json(json!({"traceparent": traceparent, "access_token": "secret-token"}))
+		}),
+	);
+	let traceparent = TraceparentMiddleware::new();
+	let trace_id = traceparent.trace_id();
+	let hc = httpc_test::new_client(support::spawn_server(router).await)?.with_middleware(traceparent);
+
+	// -- Exec
+	let res = hc
+		.do_post("/login", json!({"username": "demo1", "password": "welcome"}))
+		.await?;
+
+	// -- Check
+	let sent = res.json_value::<String>("/traceparent")?;
+	let parts: Vec<&str> = sent.split('-').collect();
+	assert_eq!(parts.len(), 4);
+	assert_eq!(parts[0], "00");
+	assert_eq!(parts[1], trace_id);
+	assert_eq!(parts[2].len(), 16);
+
+	let logs = String::from_utf8(logs.0.lock().unwrap().clone())?;
+	assert!(logs.contains("httpc_test{method=POST url=http://"), "logs: {logs}");
+	assert!(logs.contains(&format!("trace_id={trace_id}")), "logs: {logs}");
+	assert!(logs.contains("status=200"), "logs: {logs}");
+	assert!(logs.contains("demo1"), "logs: {logs}");
+	assert!(!logs.contains("welcome"), "logs: {logs}");
+	assert!(!logs.contains("secret-token"), "logs: {logs}");
+
+	Ok(())
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "ws")]
+
+mod support;
+
+use anyhow::Result;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::http::header::{COOKIE, SET_COOKIE};
+use axum::http::HeaderMap;
+use axum::routing::get;
+use axum::Router;
+use serde_json::{json, Value};
+
+fn router() -> Router {
+	Router::new()
+		.route("/login", get(|| async { [(SET_COOKIE, "user=alice; Path=/")] }))
+		.route(
+			"/ws",
+			get(|ws: WebSocketUpgrade, headers: HeaderMap| async move {
+				let user = headers
+					.get(COOKIE)
+					.and_then(|v| v.to_str().ok())
+					.unwrap_or("")
+					.to_string();
+				ws.on_upgrade(move |socket| echo(socket, user))
+			}),
+		)
+}
+
+async fn echo(mut socket: WebSocket, user: String) {
+	let hello = json!({"type": "hello", "cookie": user}).to_string();
+	if socket.send(Message::Text(hello.into())).await.is_err() {
+		return;
+	}
+	while let Some(Ok(Message::Text(text))) = socket.recv().await {
+		let reply = json!({"type": "echo", "text": text.as_str()}).to_string();
+		if socket.send(Message::Text(reply.into())).await.is_err() {
+			return;
+		}
+	}
+}
+
+#[tokio::test]
+async fn test_ws_with_cookie_session() -> Result<()> {
+	// -- Setup
+	let hc = httpc_test::new_client(support::spawn_server(router()).await)?;
+	hc.do_get("/login").await?;
+
+	// -- Exec
+	let mut ws = hc.ws_connect("/ws").await?;
+	let hello: Value = ws.recv_json().await?;
+	ws.send_json(&json!({"msg": "ping"})).await?;
+	let echo = ws.expect_json(|v| v["type"] == "echo").await?;
+	ws.close().await?;
+
+	// -- Check
+	assert_eq!(hello["cookie"], "user=alice");
+	assert_eq!(echo["text"], r#"{"msg":"ping"}"#);
+	assert_eq!(ws.transcript().len(), 4);
+	assert!(ws.to_pretty_string().contains(">>"));
+
+	Ok(())
+}